@@ -0,0 +1,177 @@
+use std::fmt;
+
+use crate::dsl::parser::ParseError;
+use crate::ir::span::Span;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum TokenKind {
+    Ident(String),
+    Number(u64),
+    Symbol(char),
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenKind::Ident(name) => write!(f, "{}", name),
+            TokenKind::Number(number) => write!(f, "{}", number),
+            TokenKind::Symbol(symbol) => write!(f, "{}", symbol),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+}
+
+/// Splits SC-DSL source into identifiers, numbers and the symbols used in species and signatures.
+/// `#` starts a comment running to the end of the line.
+pub fn tokenize(source: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+    let mut line = 1;
+    let mut line_start = 0;
+
+    while let Some(&(start, c)) = chars.peek() {
+        let col = source[line_start..start].chars().count() + 1;
+
+        if c == '\n' {
+            chars.next();
+            line += 1;
+            line_start = start + 1;
+        } else if c.is_whitespace() {
+            chars.next();
+        } else if c == '#' {
+            while let Some(&(_, c)) = chars.peek() {
+                if c == '\n' {
+                    break;
+                }
+                chars.next();
+            }
+        } else if c.is_alphabetic() || c == '_' {
+            let mut end = start;
+            while let Some(&(i, c)) = chars.peek() {
+                if !(c.is_alphanumeric() || c == '_') {
+                    break;
+                }
+                end = i + c.len_utf8();
+                chars.next();
+            }
+            tokens.push(Token {
+                kind: TokenKind::Ident(source[start..end].to_string()),
+                span: Span { file: None, start, end, line, col },
+            });
+        } else if c.is_ascii_digit() {
+            let mut end = start;
+            while let Some(&(i, c)) = chars.peek() {
+                if !c.is_ascii_digit() {
+                    break;
+                }
+                end = i + 1;
+                chars.next();
+            }
+            let number = source[start..end].parse().map_err(|_| ParseError {
+                message: format!("number '{}' is too large", &source[start..end]),
+                file: None,
+                line,
+                col,
+            })?;
+            tokens.push(Token {
+                kind: TokenKind::Number(number),
+                span: Span { file: None, start, end, line, col },
+            });
+        } else if matches!(c, '<' | '>' | ',' | '(' | ')' | ':' | '!') {
+            chars.next();
+            tokens.push(Token {
+                kind: TokenKind::Symbol(c),
+                span: Span { file: None, start, end: start + 1, line, col },
+            });
+        } else {
+            return Err(ParseError {
+                message: format!("unexpected character '{}'", c),
+                file: None,
+                line,
+                col,
+            });
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn idents(source: &str) -> Vec<String> {
+        tokenize(source)
+            .unwrap()
+            .into_iter()
+            .map(|token| token.kind.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_tokenize_words() {
+        assert_eq!(idents("bug Bird end"), vec!["bug", "Bird", "end"]);
+    }
+
+    #[test]
+    fn test_tokenize_skips_comments() {
+        assert_eq!(idents("bug Bird # a bird\nend"), vec!["bug", "Bird", "end"]);
+    }
+
+    #[test]
+    fn test_tokenize_positions() {
+        let tokens = tokenize("bug Bird\n  gene energy Int").unwrap();
+
+        assert_eq!((tokens[1].span.start, tokens[1].span.end), (4, 8));
+        assert_eq!((tokens[2].span.line, tokens[2].span.col), (2, 3));
+        assert_eq!((tokens[4].span.line, tokens[4].span.col), (2, 15));
+    }
+
+    #[test]
+    fn test_tokenize_symbols() {
+        let tokens = tokenize("Map<Str,List<Int>>").unwrap();
+
+        assert_eq!(tokens.len(), 9);
+        assert_eq!(tokens[1].kind, TokenKind::Symbol('<'));
+        assert_eq!(tokens[3].kind, TokenKind::Symbol(','));
+        assert_eq!(tokens[7].kind, TokenKind::Symbol('>'));
+        assert_eq!((tokens[8].span.start, tokens[8].span.end), (17, 18));
+    }
+
+    #[test]
+    fn test_tokenize_signature_symbols() {
+        let kinds: Vec<TokenKind> = tokenize("fly(h Int): Bool ! Tired").unwrap().into_iter().map(|t| t.kind).collect();
+
+        assert_eq!(kinds[1], TokenKind::Symbol('('));
+        assert_eq!(kinds[4], TokenKind::Symbol(')'));
+        assert_eq!(kinds[5], TokenKind::Symbol(':'));
+        assert_eq!(kinds[7], TokenKind::Symbol('!'));
+    }
+
+    #[test]
+    fn test_tokenize_numbers() {
+        let tokens = tokenize("Decimal<10, 2>").unwrap();
+
+        assert_eq!(tokens[2].kind, TokenKind::Number(10));
+        assert_eq!(tokens[4].kind, TokenKind::Number(2));
+        assert_eq!(tokenize("99999999999999999999").unwrap_err().message, "number '99999999999999999999' is too large");
+    }
+
+    #[test]
+    fn test_tokenize_unicode_identifiers() {
+        assert_eq!(idents("bug Pássaro gene 名前 Str"), vec!["bug", "Pássaro", "gene", "名前", "Str"]);
+    }
+
+    #[test]
+    fn test_tokenize_rejects_unknown_character() {
+        let error = tokenize("bug Bird\n  gene energy @").unwrap_err();
+
+        assert_eq!((error.line, error.col), (2, 15));
+        assert!(error.message.contains('@'));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod lexer;
+pub mod parser;
+pub mod tree;
+
+pub use parser::ParseError;
+pub use tree::{Bug, Ethics, Gene, Param, Specie, Tree};
@@ -0,0 +1,485 @@
+use std::fmt;
+
+use crate::dsl::lexer::{tokenize, Token, TokenKind};
+use crate::dsl::tree::{Bug, Ethics, Gene, Param, Specie, Tree};
+use crate::ir::span::Span;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub file: Option<String>,
+    pub line: usize,
+    pub col: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}: {}", file, self.line, self.col, self.message),
+            None => write!(f, "{}:{}: {}", self.line, self.col, self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Recursive-descent parser for the `bug … gene … ethics … end` syntax.
+pub struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    eof: (usize, usize),
+    file: Option<String>,
+}
+
+impl Parser {
+    pub fn new(source: &str) -> Result<Parser, ParseError> {
+        let line = source.matches('\n').count() + 1;
+        let col = source.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+
+        Ok(Parser {
+            tokens: tokenize(source)?,
+            pos: 0,
+            eof: (line, col),
+            file: None,
+        })
+    }
+
+    pub fn with_file(source: &str, file: &str) -> Result<Parser, ParseError> {
+        let mut parser = Parser::new(source).map_err(|error| ParseError { file: Some(file.to_string()), ..error })?;
+        parser.file = Some(file.to_string());
+        Ok(parser)
+    }
+
+    pub fn parse_tree(&mut self) -> Result<Tree, ParseError> {
+        let mut bugs = Vec::new();
+
+        while self.peek().is_some() {
+            bugs.push(self.parse_bug()?);
+        }
+
+        Ok(Tree { bugs })
+    }
+
+    fn parse_bug(&mut self) -> Result<Bug, ParseError> {
+        let start = self.expect_keyword("bug")?;
+        let name = self.expect_ident("bug name")?;
+        let mut generics = Vec::new();
+        if self.eat_symbol('<') {
+            generics.push(self.parse_type_param()?);
+            while self.eat_symbol(',') {
+                generics.push(self.parse_type_param()?);
+            }
+            self.expect_symbol('>')?;
+        }
+        let mut genes = Vec::new();
+        let mut ethics = Vec::new();
+
+        loop {
+            match self.peek_ident() {
+                Some("gene") => {
+                    let start = self.advance();
+                    let name = self.expect_ident("gene name")?;
+                    let specie = self.parse_specie()?;
+                    genes.push(Gene { name, specie, span: start.to(&self.previous()) });
+                }
+                Some("ethics") => ethics.push(self.parse_ethics()?),
+                Some("end") => {
+                    let end = self.advance();
+                    return Ok(Bug { name, generics, genes, ethics, span: start.to(&end) });
+                }
+                _ => return Err(self.error("expected 'gene', 'ethics' or 'end'")),
+            }
+        }
+    }
+
+    /// A type parameter of a generic bug, which may not shadow a builtin specie.
+    fn parse_type_param(&mut self) -> Result<String, ParseError> {
+        let start = self.pos;
+        let name = self.expect_ident("type parameter")?;
+        let builtin = matches!(name.as_str(), "List" | "Optional" | "Map" | "Decimal") || !matches!(Specie::from_name(&name), Specie::Bug(_));
+        if builtin {
+            return Err(self.error_at(start, &format!("type parameter '{}' shadows a builtin specie", name)));
+        }
+        Ok(name)
+    }
+
+    /// `ethics name(param Specie, …): Specie ! Fault, …`, where the parameter list,
+    /// return specie and faults are each optional.
+    fn parse_ethics(&mut self) -> Result<Ethics, ParseError> {
+        let start = self.advance();
+        let name = self.expect_ident("ethics name")?;
+        let mut params = Vec::new();
+        let mut returns = None;
+        let mut faults = Vec::new();
+
+        if self.eat_symbol('(') && !self.eat_symbol(')') {
+            loop {
+                let param_start = self.pos;
+                let name = self.expect_ident("parameter name")?;
+                let specie = self.parse_specie()?;
+                let span = self.tokens[param_start].span.to(&self.previous());
+                params.push(Param { name, specie, span: Span { file: self.file.clone(), ..span } });
+                if !self.eat_symbol(',') {
+                    self.expect_symbol(')')?;
+                    break;
+                }
+            }
+        }
+        if self.eat_symbol(':') {
+            returns = Some(self.parse_specie()?);
+        }
+        if self.eat_symbol('!') {
+            faults.push(self.expect_ident("fault name")?);
+            while self.eat_symbol(',') {
+                faults.push(self.expect_ident("fault name")?);
+            }
+        }
+
+        Ok(Ethics { name, params, returns, faults, span: start.to(&self.previous()) })
+    }
+
+    /// `Name` or `Name<Specie, …>`; only `List`, `Optional` and `Map` take species as arguments,
+    /// and `Decimal<precision, scale>` takes two numbers.
+    fn parse_specie(&mut self) -> Result<Specie, ParseError> {
+        let start = self.pos;
+        let name = self.expect_ident("specie")?;
+
+        if name == "Decimal" && self.eat_symbol('<') {
+            let precision = self.expect_number("decimal precision")?;
+            self.expect_symbol(',')?;
+            let scale = self.expect_number("decimal scale")?;
+            self.expect_symbol('>')?;
+            return Ok(Specie::Decimal { precision, scale });
+        }
+
+        if !self.eat_symbol('<') {
+            return match name.as_str() {
+                "List" | "Optional" | "Map" | "Decimal" => Err(self.error_at(start, &format!("'{}' needs type arguments", name))),
+                _ => Ok(Specie::from_name(&name)),
+            };
+        }
+
+        let mut args = vec![self.parse_specie()?];
+        while self.eat_symbol(',') {
+            args.push(self.parse_specie()?);
+        }
+        if !self.eat_symbol('>') {
+            return Err(self.error("expected ',' or '>'"));
+        }
+
+        let arity = args.len();
+        match (name.as_str(), arity) {
+            ("List" | "Optional" | "Map", _) => {}
+            _ => {
+                return match Specie::from_name(&name) {
+                    Specie::Bug(bug) => Ok(Specie::Apply { bug, args }),
+                    _ => Err(self.error_at(start, &format!("'{}' does not take type arguments", name))),
+                };
+            }
+        }
+        let mut args = args.into_iter().map(Box::new);
+        match (name.as_str(), arity) {
+            ("List", 1) => Ok(Specie::List(args.next().unwrap())),
+            ("Optional", 1) => Ok(Specie::Optional(args.next().unwrap())),
+            ("Map", 2) => Ok(Specie::Map(args.next().unwrap(), args.next().unwrap())),
+            ("Map", _) => Err(self.error_at(start, &format!("'Map' takes 2 type arguments, found {}", arity))),
+            _ => Err(self.error_at(start, &format!("'{}' takes 1 type argument, found {}", name, arity))),
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> Result<(), ParseError> {
+        if self.eat_symbol(symbol) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", symbol)))
+        }
+    }
+
+    fn expect_number(&mut self, what: &str) -> Result<u8, ParseError> {
+        match self.peek().map(|token| &token.kind) {
+            Some(&TokenKind::Number(number)) => {
+                let number = u8::try_from(number).map_err(|_| self.error(&format!("{} must be at most {}", what, u8::MAX)))?;
+                self.pos += 1;
+                Ok(number)
+            }
+            _ => Err(self.error(&format!("expected {}", what))),
+        }
+    }
+
+    fn eat_symbol(&mut self, symbol: char) -> bool {
+        if self.peek().map(|token| &token.kind) == Some(&TokenKind::Symbol(symbol)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_ident(&self) -> Option<&str> {
+        self.peek().and_then(|token| match &token.kind {
+            TokenKind::Ident(name) => Some(name.as_str()),
+            TokenKind::Number(_) | TokenKind::Symbol(_) => None,
+        })
+    }
+
+    /// Consumes the current token and returns its span, tagged with the parser's file.
+    fn advance(&mut self) -> Span {
+        self.pos += 1;
+        self.previous()
+    }
+
+    fn previous(&self) -> Span {
+        Span {
+            file: self.file.clone(),
+            ..self.tokens[self.pos - 1].span.clone()
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<Span, ParseError> {
+        if self.peek_ident() == Some(keyword) {
+            Ok(self.advance())
+        } else {
+            Err(self.error(&format!("expected '{}'", keyword)))
+        }
+    }
+
+    fn expect_ident(&mut self, what: &str) -> Result<String, ParseError> {
+        match self.peek_ident() {
+            Some(name) if !is_keyword(name) => {
+                let name = name.to_string();
+                self.pos += 1;
+                Ok(name)
+            }
+            _ => Err(self.error(&format!("expected {}", what))),
+        }
+    }
+
+    fn error(&self, expected: &str) -> ParseError {
+        match self.peek() {
+            Some(token) => ParseError {
+                message: format!("{}, found '{}'", expected, token.kind),
+                file: self.file.clone(),
+                line: token.span.line,
+                col: token.span.col,
+            },
+            None => ParseError {
+                message: format!("{}, found end of input", expected),
+                file: self.file.clone(),
+                line: self.eof.0,
+                col: self.eof.1,
+            },
+        }
+    }
+
+    fn error_at(&self, pos: usize, message: &str) -> ParseError {
+        let span = &self.tokens[pos].span;
+        ParseError {
+            message: message.to_string(),
+            file: self.file.clone(),
+            line: span.line,
+            col: span.col,
+        }
+    }
+}
+
+fn is_keyword(word: &str) -> bool {
+    matches!(word, "bug" | "gene" | "ethics" | "end")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str) -> Result<Tree, ParseError> {
+        Parser::new(source)?.parse_tree()
+    }
+
+    #[test]
+    fn test_parse_empty_source() {
+        assert_eq!(parse("").unwrap(), Tree { bugs: vec![] });
+        assert_eq!(parse("# nothing here\n").unwrap(), Tree { bugs: vec![] });
+    }
+
+    #[test]
+    fn test_parse_single_bug() {
+        let tree = parse("
+            bug Bird
+                gene energy Int
+                gene nest Nest
+                ethics fly
+            end
+        ").unwrap();
+
+        let bug = &tree.bugs[0];
+
+        assert_eq!(tree.bugs.len(), 1);
+        assert_eq!(bug.name, "Bird");
+        assert_eq!(
+            bug.genes.iter().map(|g| (g.name.as_str(), g.specie.clone())).collect::<Vec<_>>(),
+            vec![("energy", Specie::Int), ("nest", Specie::Bug("Nest".to_string()))]
+        );
+        assert_eq!(bug.ethics[0].name, "fly");
+    }
+
+    #[test]
+    fn test_parse_spans() {
+        let source = "bug Bird\n  gene energy Int\n  ethics fly\nend";
+        let tree = parse(source).unwrap();
+        let bug = &tree.bugs[0];
+
+        assert_eq!(&source[bug.span.start..bug.span.end], source);
+        assert_eq!(&source[bug.genes[0].span.start..bug.genes[0].span.end], "gene energy Int");
+        assert_eq!((bug.genes[0].span.line, bug.genes[0].span.col), (2, 3));
+        assert_eq!(&source[bug.ethics[0].span.start..bug.ethics[0].span.end], "ethics fly");
+        assert_eq!(bug.ethics[0].span.file, None);
+    }
+
+    #[test]
+    fn test_parse_multiple_bugs_on_one_line() {
+        let tree = parse("bug Ant gene alive Bool end bug Bee ethics sting end").unwrap();
+
+        assert_eq!(tree.bugs.len(), 2);
+        assert_eq!(tree.bugs[0].genes[0].specie, Specie::Bool);
+        assert_eq!(tree.bugs[1].ethics[0].name, "sting");
+    }
+
+    #[test]
+    fn test_parse_interleaved_genes_and_ethics_keep_order() {
+        let tree = parse("bug Bird ethics fly gene energy Int ethics sing gene name Str end").unwrap();
+        let bug = &tree.bugs[0];
+
+        assert_eq!(bug.genes.iter().map(|g| g.name.as_str()).collect::<Vec<_>>(), vec!["energy", "name"]);
+        assert_eq!(bug.ethics.iter().map(|e| e.name.as_str()).collect::<Vec<_>>(), vec!["fly", "sing"]);
+    }
+
+    #[test]
+    fn test_parse_ethics_signature() {
+        let source = "bug Bird ethics fly(height Int, speed U8): Bool ! Tired, Grounded ethics rest() ethics sing end";
+        let tree = parse(source).unwrap();
+        let fly = &tree.bugs[0].ethics[0];
+
+        assert_eq!(fly.params.iter().map(|p| (p.name.as_str(), p.specie.clone())).collect::<Vec<_>>(), vec![
+            ("height", Specie::Int),
+            ("speed", Specie::U8),
+        ]);
+        assert_eq!(&source[fly.params[1].span.start..fly.params[1].span.end], "speed U8");
+        assert_eq!(fly.returns, Some(Specie::Bool));
+        assert_eq!(fly.faults, vec!["Tired", "Grounded"]);
+        assert_eq!(&source[fly.span.start..fly.span.end], "ethics fly(height Int, speed U8): Bool ! Tired, Grounded");
+
+        let rest = &tree.bugs[0].ethics[1];
+        assert!(rest.params.is_empty() && rest.returns.is_none() && rest.faults.is_empty());
+        assert_eq!(tree.bugs[0].ethics[2].name, "sing");
+    }
+
+    #[test]
+    fn test_parse_ethics_signature_errors() {
+        assert_eq!(parse("bug Bird ethics fly(height) end").unwrap_err().message, "expected specie, found ')'");
+        assert_eq!(parse("bug Bird ethics fly(height Int end").unwrap_err().message, "expected ')', found 'end'");
+        assert_eq!(parse("bug Bird ethics fly ! end").unwrap_err().message, "expected fault name, found 'end'");
+    }
+
+    #[test]
+    fn test_parse_missing_end() {
+        let error = parse("bug Bird\n  gene energy Int\n").unwrap_err();
+
+        assert_eq!(error.message, "expected 'gene', 'ethics' or 'end', found end of input");
+        assert_eq!((error.line, error.col), (3, 1));
+    }
+
+    #[test]
+    fn test_parse_missing_specie() {
+        let error = parse("bug Bird\n  gene energy\nend").unwrap_err();
+
+        assert_eq!(error.message, "expected specie, found 'end'");
+        assert_eq!((error.line, error.col), (3, 1));
+    }
+
+    #[test]
+    fn test_parse_collection_species() {
+        let tree = parse("bug Hive gene bees List<Bee> gene nickname Optional<Str> gene scores Map<Str, List<Int>> end").unwrap();
+        let species: Vec<Specie> = tree.bugs[0].genes.iter().map(|g| g.specie.clone()).collect();
+
+        assert_eq!(species, vec![
+            Specie::List(Box::new(Specie::Bug("Bee".to_string()))),
+            Specie::Optional(Box::new(Specie::Str)),
+            Specie::Map(Box::new(Specie::Str), Box::new(Specie::List(Box::new(Specie::Int)))),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_collection_arity_errors() {
+        assert_eq!(parse("bug Hive gene bees List end").unwrap_err().message, "'List' needs type arguments");
+        assert_eq!(parse("bug Hive gene bees List<Bee, Int> end").unwrap_err().message, "'List' takes 1 type argument, found 2");
+        assert_eq!(parse("bug Hive gene bees Map<Str> end").unwrap_err().message, "'Map' takes 2 type arguments, found 1");
+        assert_eq!(parse("bug Hive gene bees Int<Bee> end").unwrap_err().message, "'Int' does not take type arguments");
+    }
+
+    #[test]
+    fn test_parse_generic_bugs() {
+        let tree = parse("bug Page<T, K> gene items List<T> gene next Optional<Page<T, K>> end bug Feed gene birds Page<Bird, Int> end").unwrap();
+
+        assert_eq!(tree.bugs[0].generics, vec!["T".to_string(), "K".to_string()]);
+        assert_eq!(tree.bugs[1].generics, Vec::<String>::new());
+        assert_eq!(tree.bugs[1].genes[0].specie, Specie::Apply {
+            bug: "Page".to_string(),
+            args: vec![Specie::Bug("Bird".to_string()), Specie::Int],
+        });
+        assert_eq!(parse("bug Page<> end").unwrap_err().message, "expected type parameter, found '>'");
+    }
+
+    #[test]
+    fn test_parse_rejects_builtin_type_parameters() {
+        let error = parse("bug Box<T, Int> gene item T end").unwrap_err();
+
+        assert_eq!(error.message, "type parameter 'Int' shadows a builtin specie");
+        assert_eq!((error.line, error.col), (1, 12));
+        assert_eq!(parse("bug Box<List> end").unwrap_err().message, "type parameter 'List' shadows a builtin specie");
+    }
+
+    #[test]
+    fn test_parse_rich_scalar_species() {
+        let tree = parse("bug Ledger gene amount Decimal<12, 2> gene at DateTime gene id Uuid gene count U32 end").unwrap();
+        let species: Vec<Specie> = tree.bugs[0].genes.iter().map(|g| g.specie.clone()).collect();
+
+        assert_eq!(species, vec![
+            Specie::Decimal { precision: 12, scale: 2 },
+            Specie::DateTime,
+            Specie::Uuid,
+            Specie::U32,
+        ]);
+    }
+
+    #[test]
+    fn test_parse_decimal_errors() {
+        assert_eq!(parse("bug Ledger gene amount Decimal end").unwrap_err().message, "'Decimal' needs type arguments");
+        assert_eq!(parse("bug Ledger gene amount Decimal<12> end").unwrap_err().message, "expected ',', found '>'");
+        assert_eq!(parse("bug Ledger gene amount Decimal<300, 2> end").unwrap_err().message, "decimal precision must be at most 255, found '300'");
+        assert_eq!(parse("bug Ledger gene amount Decimal<Int, 2> end").unwrap_err().message, "expected decimal precision, found 'Int'");
+    }
+
+    #[test]
+    fn test_parse_unclosed_type_arguments() {
+        let error = parse("bug Hive gene bees List<Bee end").unwrap_err();
+
+        assert_eq!(error.message, "expected ',' or '>', found 'end'");
+    }
+
+    #[test]
+    fn test_parse_rejects_keyword_as_name() {
+        let error = parse("bug end").unwrap_err();
+
+        assert_eq!(error.message, "expected bug name, found 'end'");
+    }
+
+    #[test]
+    fn test_parse_top_level_must_be_bug() {
+        let error = parse("gene energy Int").unwrap_err();
+
+        assert_eq!(error.to_string(), "1:1: expected 'bug', found 'gene'");
+    }
+}
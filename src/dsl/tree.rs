@@ -0,0 +1,135 @@
+use crate::dsl::parser::{ParseError, Parser};
+use crate::ir::span::Span;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Tree {
+    pub bugs: Vec<Bug>,
+}
+
+impl Tree {
+    pub fn parse_input(input: String) -> Result<Tree, ParseError> {
+        Parser::new(&input)?.parse_tree()
+    }
+
+    /// Like `parse_input`, but every span produced records `file` as its origin.
+    pub fn parse_file(file: &str, input: String) -> Result<Tree, ParseError> {
+        Parser::with_file(&input, file)?.parse_tree()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Bug {
+    pub name: String,
+    pub generics: Vec<String>,
+    pub genes: Vec<Gene>,
+    pub ethics: Vec<Ethics>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Gene {
+    pub name: String,
+    pub specie: Specie,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Ethics {
+    pub name: String,
+    pub params: Vec<Param>,
+    pub returns: Option<Specie>,
+    pub faults: Vec<String>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Param {
+    pub name: String,
+    pub specie: Specie,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Specie {
+    Int,
+    Str,
+    Bool,
+    Float,
+    Decimal { precision: u8, scale: u8 },
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    Date,
+    DateTime,
+    Duration,
+    Uuid,
+    Bytes,
+    Bug(String),
+    List(Box<Specie>),
+    Optional(Box<Specie>),
+    Map(Box<Specie>, Box<Specie>),
+    Apply { bug: String, args: Vec<Specie> },
+}
+
+impl Specie {
+    pub fn from_name(name: &str) -> Specie {
+        match name {
+            "Int" => Specie::Int,
+            "Str" => Specie::Str,
+            "Bool" => Specie::Bool,
+            "Float" => Specie::Float,
+            "I8" => Specie::I8,
+            "I16" => Specie::I16,
+            "I32" => Specie::I32,
+            "I64" => Specie::I64,
+            "U8" => Specie::U8,
+            "U16" => Specie::U16,
+            "U32" => Specie::U32,
+            "U64" => Specie::U64,
+            "Date" => Specie::Date,
+            "DateTime" => Specie::DateTime,
+            "Duration" => Specie::Duration,
+            "Uuid" => Specie::Uuid,
+            "Bytes" => Specie::Bytes,
+            other => Specie::Bug(other.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_specie_from_name() {
+        assert_eq!(Specie::from_name("Int"), Specie::Int);
+        assert_eq!(Specie::from_name("Str"), Specie::Str);
+        assert_eq!(Specie::from_name("Bool"), Specie::Bool);
+        assert_eq!(Specie::from_name("U16"), Specie::U16);
+        assert_eq!(Specie::from_name("DateTime"), Specie::DateTime);
+        assert_eq!(Specie::from_name("Nest"), Specie::Bug("Nest".to_string()));
+    }
+
+    #[test]
+    fn test_tree_parse_input() {
+        let tree = Tree::parse_input("bug Bird\n  gene energy Int\n  ethics fly\nend".to_string()).unwrap();
+
+        assert_eq!(tree.bugs.len(), 1);
+        assert_eq!(tree.bugs[0].name, "Bird");
+        assert_eq!(tree.bugs[0].genes[0].specie, Specie::Int);
+        assert_eq!(tree.bugs[0].ethics[0].name, "fly");
+    }
+
+    #[test]
+    fn test_tree_parse_file_records_file() {
+        let tree = Tree::parse_file("bird.sc", "bug Bird\n  gene energy Int\nend".to_string()).unwrap();
+
+        assert_eq!(tree.bugs[0].span.file.as_deref(), Some("bird.sc"));
+        assert_eq!(tree.bugs[0].genes[0].span.to_string(), "bird.sc:2:3");
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ir::{IR, alveolus::Alveolus, attribute::Attributes, flora::Flora, seal::Seal, span::Span};
+
+/// A named domain type over a base flora, such as `Email = Str` with a `Pattern` seal.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Alias {
+    pub primor: String,
+    pub flora: Flora,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub seals: Vec<Seal>,
+    #[serde(default, skip_serializing_if = "AliasKind::is_default")]
+    pub kind: AliasKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+/// Whether generated code should use the base type directly or wrap it in a distinct type.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum AliasKind {
+    #[default]
+    Transparent,
+    Nominal,
+}
+
+impl AliasKind {
+    pub fn is_default(&self) -> bool {
+        *self == AliasKind::Transparent
+    }
+}
+
+impl Alias {
+    /// A transparent alias without seals.
+    pub fn new(primor: impl Into<String>, flora: Flora) -> Alias {
+        Alias { primor: primor.into(), flora, seals: vec![], kind: AliasKind::Transparent, doc: None, attributes: Attributes::new(), span: None }
+    }
+}
+
+impl IR {
+    pub fn alias(&self, name: &str) -> Option<&Alias> {
+        self.alveolus.iter().find_map(|alveolus| match alveolus {
+            Alveolus::Alias(alias) if alias.primor == name => Some(alias),
+            _ => None,
+        })
+    }
+
+    /// The representation behind `flora`: every alias it mentions, at any depth, is replaced by
+    /// its base flora, following chains of aliases. An alias that loops back on itself is kept.
+    pub fn underlying(&self, flora: &Flora) -> Flora {
+        self.expand(flora, &mut HashSet::new())
+    }
+
+    /// Seals a value of alias `name` must satisfy, from the innermost base alias outwards.
+    pub fn alias_seals(&self, name: &str) -> Vec<&Seal> {
+        let mut seen = HashSet::new();
+        let mut chain = Vec::new();
+        let mut next = self.alias(name);
+        while let Some(alias) = next {
+            if !seen.insert(alias.primor.as_str()) {
+                break;
+            }
+            chain.push(alias);
+            next = match &alias.flora {
+                Flora::Bug(base) => self.alias(base),
+                _ => None,
+            };
+        }
+        chain.iter().rev().flat_map(|alias| &alias.seals).collect()
+    }
+
+    fn expand<'a>(&'a self, flora: &Flora, active: &mut HashSet<&'a str>) -> Flora {
+        flora.rewrite(&mut |node| {
+            let Flora::Bug(name) = node else { return None };
+            let alias = self.alias(name)?;
+            if !active.insert(alias.primor.as_str()) {
+                return None;
+            }
+            let base = self.expand(&alias.flora, active);
+            active.remove(alias.primor.as_str());
+            Some(base)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::literal::Literal;
+    use serde_json;
+
+    fn alias(primor: &str, flora: Flora, seals: Vec<Seal>, kind: AliasKind) -> Alveolus {
+        Alveolus::Alias(Alias { seals, kind, ..Alias::new(primor, flora) })
+    }
+
+    #[test]
+    fn test_underlying_follows_alias_chains() {
+        let ir = IR {
+            alveolus: vec![
+                alias("Email", Flora::Str, vec![Seal::Pattern("^.+@.+$".to_string())], AliasKind::Transparent),
+                alias("WorkEmail", Flora::Bug("Email".to_string()), vec![Seal::Length { min: None, max: Some(64) }], AliasKind::Nominal),
+            ],
+        };
+
+        assert_eq!(ir.underlying(&Flora::Bug("WorkEmail".to_string())), Flora::Str);
+        assert_eq!(
+            ir.underlying(&Flora::List(Box::new(Flora::Bug("Email".to_string())))),
+            Flora::List(Box::new(Flora::Str))
+        );
+        assert_eq!(ir.underlying(&Flora::Bug("Bird".to_string())), Flora::Bug("Bird".to_string()));
+        assert_eq!(ir.alias_seals("WorkEmail"), vec![
+            &Seal::Pattern("^.+@.+$".to_string()),
+            &Seal::Length { min: None, max: Some(64) },
+        ]);
+        assert!(ir.alias_seals("Bird").is_empty());
+    }
+
+    #[test]
+    fn test_underlying_stops_at_cycles() {
+        let ir = IR {
+            alveolus: vec![
+                alias("Ping", Flora::Bug("Pong".to_string()), vec![], AliasKind::Transparent),
+                alias("Pong", Flora::Bug("Ping".to_string()), vec![], AliasKind::Transparent),
+            ],
+        };
+
+        assert_eq!(ir.underlying(&Flora::Bug("Ping".to_string())), Flora::Bug("Ping".to_string()));
+        assert!(ir.alias_seals("Ping").is_empty());
+    }
+
+    #[test]
+    fn test_alias_json_format() {
+        let energy = Alias {
+            seals: vec![Seal::Range { min: Some(Literal::Int(0)), max: Some(Literal::Int(1000)) }],
+            kind: AliasKind::Nominal,
+            ..Alias::new("Energy", Flora::Int)
+        };
+
+        let json = serde_json::to_string(&energy).unwrap();
+
+        assert_eq!(
+            json,
+            "{\"primor\":\"Energy\",\"flora\":\"Int\",\"seals\":[{\"Range\":{\"min\":{\"Int\":0},\"max\":{\"Int\":1000}}}],\"kind\":\"Nominal\"}"
+        );
+        assert_eq!(serde_json::from_str::<Alias>(&json).unwrap(), energy);
+        let email = Alias::new("Email", Flora::Str);
+        assert_eq!(serde_json::to_string(&email).unwrap(), "{\"primor\":\"Email\",\"flora\":\"Str\"}");
+        assert_eq!(serde_json::from_str::<Alias>("{\"primor\":\"Email\",\"flora\":\"Str\"}").unwrap(), email);
+    }
+}
@@ -1,15 +1,83 @@
 use serde::{Deserialize, Serialize};
+use crate::ir::alias::Alias;
+use crate::ir::enumeration::Enumeration;
+use crate::ir::event::Event;
+use crate::ir::interface::Interface;
 use crate::ir::larvie::Larvie;
+use crate::ir::lifecycle::Lifecycle;
+use crate::ir::mixin::Mixin;
+use crate::ir::module::Module;
+use crate::ir::span::Span;
+use crate::ir::union::Union;
+use crate::ir::view::View;
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Alveolus {
     Larvie(Larvie),
+    Enumeration(Enumeration),
+    Union(Union),
+    Interface(Interface),
+    Mixin(Mixin),
+    Alias(Alias),
+    Module(Module),
+    Lifecycle(Lifecycle),
+    Event(Event),
+    View(View),
+}
+
+impl Alveolus {
+    /// The name `Flora::Bug` uses to refer to this alveolus.
+    pub fn primor(&self) -> &str {
+        match self {
+            Alveolus::Larvie(larvie) => &larvie.primor,
+            Alveolus::Enumeration(enumeration) => &enumeration.primor,
+            Alveolus::Union(union) => &union.primor,
+            Alveolus::Interface(interface) => &interface.primor,
+            Alveolus::Mixin(mixin) => &mixin.primor,
+            Alveolus::Alias(alias) => &alias.primor,
+            Alveolus::Module(module) => &module.primor,
+            Alveolus::Lifecycle(lifecycle) => &lifecycle.primor,
+            Alveolus::Event(event) => &event.primor,
+            Alveolus::View(view) => &view.primor,
+        }
+    }
+
+    /// Lowercase name of the variant, for messages.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Alveolus::Larvie(_) => "larvie",
+            Alveolus::Enumeration(_) => "enumeration",
+            Alveolus::Union(_) => "union",
+            Alveolus::Interface(_) => "interface",
+            Alveolus::Mixin(_) => "mixin",
+            Alveolus::Alias(_) => "alias",
+            Alveolus::Module(_) => "module",
+            Alveolus::Lifecycle(_) => "lifecycle",
+            Alveolus::Event(_) => "event",
+            Alveolus::View(_) => "view",
+        }
+    }
+
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            Alveolus::Larvie(larvie) => larvie.span.as_ref(),
+            Alveolus::Enumeration(enumeration) => enumeration.span.as_ref(),
+            Alveolus::Union(union) => union.span.as_ref(),
+            Alveolus::Interface(interface) => interface.span.as_ref(),
+            Alveolus::Mixin(mixin) => mixin.span.as_ref(),
+            Alveolus::Alias(alias) => alias.span.as_ref(),
+            Alveolus::Module(module) => module.span.as_ref(),
+            Alveolus::Lifecycle(lifecycle) => lifecycle.span.as_ref(),
+            Alveolus::Event(event) => event.span.as_ref(),
+            Alveolus::View(view) => view.span.as_ref(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ir::{flora::Flora, seal::Seal, casts::Casts, instincts::Instinct};
+    use crate::ir::{flora::Flora, seal::Seal, casts::Casts, instincts::Instinct, enumeration::Member, union::Variant};
     use serde_json;
 
     #[test]
@@ -18,6 +86,7 @@ mod tests {
             primor: "TestLarvie".to_string(),
             casts: vec![],
             instincts: vec![],
+            ..Default::default()
         };
 
         let alveolus = Alveolus::Larvie(larvie.clone());
@@ -28,6 +97,7 @@ mod tests {
                 assert_eq!(l.casts.len(), 0);
                 assert_eq!(l.instincts.len(), 0);
             }
+            _ => panic!("expected a larvie"),
         }
     }
 
@@ -37,20 +107,19 @@ mod tests {
             primor: "ComplexLarvie".to_string(),
             casts: vec![
                 Casts {
-                    primor: "field1".to_string(),
-                    flora: Flora::Int,
                     seals: vec![Seal::Vital],
+                    ..Casts::new("field1", Flora::Int)
                 },
                 Casts {
-                    primor: "field2".to_string(),
-                    flora: Flora::Str,
                     seals: vec![Seal::Core],
+                    ..Casts::new("field2", Flora::Str)
                 },
             ],
             instincts: vec![
-                Instinct { echo: "create".to_string() },
-                Instinct { echo: "read".to_string() },
+                Instinct { echo: "create".to_string(), ..Default::default() },
+                Instinct { echo: "read".to_string(), ..Default::default() },
             ],
+            ..Default::default()
         };
 
         let alveolus = Alveolus::Larvie(larvie);
@@ -63,6 +132,7 @@ mod tests {
                 assert_eq!(l.casts[0].primor, "field1");
                 assert_eq!(l.instincts[0].echo, "create");
             }
+            _ => panic!("expected a larvie"),
         }
     }
 
@@ -72,14 +142,14 @@ mod tests {
             primor: "SerializableLarvie".to_string(),
             casts: vec![
                 Casts {
-                    primor: "test_field".to_string(),
-                    flora: Flora::Bool,
                     seals: vec![Seal::Root],
+                    ..Casts::new("test_field", Flora::Bool)
                 }
             ],
             instincts: vec![
-                Instinct { echo: "test_action".to_string() }
+                Instinct { echo: "test_action".to_string(), ..Default::default() }
             ],
+            ..Default::default()
         };
 
         let alveolus = Alveolus::Larvie(larvie);
@@ -96,18 +166,21 @@ mod tests {
             primor: "SameLarvie".to_string(),
             casts: vec![],
             instincts: vec![],
+            ..Default::default()
         };
 
         let larvie2 = Larvie {
             primor: "SameLarvie".to_string(),
             casts: vec![],
             instincts: vec![],
+            ..Default::default()
         };
 
         let larvie3 = Larvie {
             primor: "DifferentLarvie".to_string(),
             casts: vec![],
             instincts: vec![],
+            ..Default::default()
         };
 
         let alveolus1 = Alveolus::Larvie(larvie1);
@@ -124,14 +197,14 @@ mod tests {
             primor: "CloneLarvie".to_string(),
             casts: vec![
                 Casts {
-                    primor: "field".to_string(),
-                    flora: Flora::Str,
                     seals: vec![Seal::Core],
+                    ..Casts::new("field", Flora::Str)
                 }
             ],
             instincts: vec![
-                Instinct { echo: "action".to_string() }
+                Instinct { echo: "action".to_string(), ..Default::default() }
             ],
+            ..Default::default()
         };
 
         let original = Alveolus::Larvie(larvie);
@@ -146,6 +219,7 @@ mod tests {
                 assert_eq!(orig.casts.len(), clone.casts.len());
                 assert_eq!(orig.instincts.len(), clone.instincts.len());
             }
+            _ => panic!("expected larvies"),
         }
     }
 
@@ -155,6 +229,7 @@ mod tests {
             primor: "DebugLarvie".to_string(),
             casts: vec![],
             instincts: vec![],
+            ..Default::default()
         };
 
         let alveolus = Alveolus::Larvie(larvie);
@@ -163,4 +238,130 @@ mod tests {
         assert!(debug_str.contains("Larvie"));
         assert!(debug_str.contains("DebugLarvie"));
     }
+
+    #[test]
+    fn test_alveolus_span() {
+        let span = Span { file: None, start: 0, end: 12, line: 1, col: 1 };
+        let alveolus = Alveolus::Larvie(Larvie {
+            primor: "Spanned".to_string(),
+            span: Some(span.clone()),
+            ..Default::default()
+        });
+
+        assert_eq!(alveolus.span(), Some(&span));
+        assert_eq!(Alveolus::Larvie(Larvie::default()).span(), None);
+    }
+
+    #[test]
+    fn test_alveolus_enumeration_variant() {
+        let alveolus = Alveolus::Enumeration(Enumeration {
+            primor: "Color".to_string(),
+            members: vec![Member { primor: "Red".to_string(), ..Default::default() }],
+            ..Default::default()
+        });
+
+        assert_eq!(alveolus.primor(), "Color");
+        assert_eq!(alveolus.kind(), "enumeration");
+        assert_eq!(alveolus.span(), None);
+
+        let json = serde_json::to_string(&alveolus).unwrap();
+        assert_eq!(json, "{\"Enumeration\":{\"primor\":\"Color\",\"members\":[{\"primor\":\"Red\"}]}}");
+        assert_eq!(serde_json::from_str::<Alveolus>(&json).unwrap(), alveolus);
+    }
+
+    #[test]
+    fn test_alveolus_union_variant() {
+        let alveolus = Alveolus::Union(Union {
+            primor: "Outcome".to_string(),
+            variants: vec![Variant { primor: "Lost".to_string(), ..Default::default() }],
+            ..Default::default()
+        });
+
+        assert_eq!(alveolus.primor(), "Outcome");
+        assert_eq!(alveolus.kind(), "union");
+
+        let json = serde_json::to_string(&alveolus).unwrap();
+        assert_eq!(json, "{\"Union\":{\"primor\":\"Outcome\",\"variants\":[{\"primor\":\"Lost\"}]}}");
+        assert_eq!(serde_json::from_str::<Alveolus>(&json).unwrap(), alveolus);
+    }
+
+    #[test]
+    fn test_alveolus_interface_variant() {
+        let alveolus = Alveolus::Interface(Interface {
+            primor: "Archivable".to_string(),
+            instincts: vec![Instinct { echo: "archive".to_string(), ..Default::default() }],
+            ..Default::default()
+        });
+
+        assert_eq!(alveolus.primor(), "Archivable");
+        assert_eq!(alveolus.kind(), "interface");
+
+        let json = serde_json::to_string(&alveolus).unwrap();
+        assert_eq!(json, "{\"Interface\":{\"primor\":\"Archivable\",\"instincts\":[{\"echo\":\"archive\"}]}}");
+        assert_eq!(serde_json::from_str::<Alveolus>(&json).unwrap(), alveolus);
+    }
+
+    #[test]
+    fn test_alveolus_mixin_variant() {
+        let alveolus = Alveolus::Mixin(Mixin { primor: "Audited".to_string(), ..Default::default() });
+
+        assert_eq!(alveolus.primor(), "Audited");
+        assert_eq!(alveolus.kind(), "mixin");
+        assert_eq!(serde_json::to_string(&alveolus).unwrap(), "{\"Mixin\":{\"primor\":\"Audited\",\"casts\":[]}}");
+    }
+
+    #[test]
+    fn test_alveolus_alias_variant() {
+        let alveolus = Alveolus::Alias(Alias::new("Email", Flora::Str));
+
+        assert_eq!(alveolus.primor(), "Email");
+        assert_eq!(alveolus.kind(), "alias");
+        assert_eq!(
+            serde_json::to_string(&alveolus).unwrap(),
+            "{\"Alias\":{\"primor\":\"Email\",\"flora\":\"Str\"}}"
+        );
+    }
+
+    #[test]
+    fn test_alveolus_module_variant() {
+        let alveolus = Alveolus::Module(Module {
+            primor: "billing".to_string(),
+            alveolus: vec![Alveolus::Larvie(Larvie { primor: "Invoice".to_string(), ..Default::default() })],
+            ..Default::default()
+        });
+
+        assert_eq!(alveolus.primor(), "billing");
+        assert_eq!(alveolus.kind(), "module");
+        assert_eq!(
+            serde_json::to_string(&alveolus).unwrap(),
+            "{\"Module\":{\"primor\":\"billing\",\"alveolus\":[{\"Larvie\":{\"primor\":\"Invoice\",\"casts\":[],\"instincts\":[]}}]}}"
+        );
+    }
+
+    #[test]
+    fn test_alveolus_lifecycle_variant() {
+        let alveolus = Alveolus::Lifecycle(Lifecycle { primor: "Growth".to_string(), larvie: "Bee".to_string(), ..Default::default() });
+
+        assert_eq!(alveolus.primor(), "Growth");
+        assert_eq!(alveolus.kind(), "lifecycle");
+        assert_eq!(alveolus.span(), None);
+    }
+
+    #[test]
+    fn test_alveolus_event_variant() {
+        let alveolus = Alveolus::Event(Event { primor: "Hatched".to_string(), ..Default::default() });
+
+        assert_eq!(alveolus.primor(), "Hatched");
+        assert_eq!(alveolus.kind(), "event");
+        assert_eq!(serde_json::to_string(&alveolus).unwrap(), "{\"Event\":{\"primor\":\"Hatched\"}}");
+    }
+
+    #[test]
+    fn test_alveolus_view_variant() {
+        let alveolus = Alveolus::View(View { primor: "PublicBird".to_string(), source: "Bird".to_string(), ..Default::default() });
+
+        assert_eq!(alveolus.primor(), "PublicBird");
+        assert_eq!(alveolus.kind(), "view");
+        assert_eq!(serde_json::to_string(&alveolus).unwrap(), "{\"View\":{\"primor\":\"PublicBird\",\"source\":\"Bird\"}}");
+    }
 }
@@ -0,0 +1,171 @@
+use std::collections::BTreeMap;
+
+use crate::ir::{
+    alias::Alias,
+    alveolus::Alveolus,
+    casts::Casts,
+    constraint::{Constraint, Index},
+    enumeration::{Enumeration, Member},
+    event::Event,
+    instincts::{Instinct, Param},
+    interface::Interface,
+    larvie::Larvie,
+    lifecycle::{Lifecycle, State, Transition},
+    literal::Literal,
+    mixin::Mixin,
+    module::{Import, Module},
+    relation::Relation,
+    union::{Union, Variant},
+    view::{Selection, View},
+};
+
+/// Tool-specific hints keyed by name, e.g. `json.rename` or `ui.hidden`.
+///
+/// Kept sorted so that the JSON form of a node does not depend on insertion order.
+pub type Attributes = BTreeMap<String, Literal>;
+
+/// A node carrying a doc string and attributes, so passes and generators can read them
+/// without matching on the kind of node.
+pub trait Annotated {
+    fn doc(&self) -> Option<&str>;
+    fn attributes(&self) -> &Attributes;
+
+    fn attribute(&self, key: &str) -> Option<&Literal> {
+        self.attributes().get(key)
+    }
+}
+
+macro_rules! annotated {
+    ($($node:ty),* $(,)?) => {
+        $(
+            impl Annotated for $node {
+                fn doc(&self) -> Option<&str> {
+                    self.doc.as_deref()
+                }
+
+                fn attributes(&self) -> &Attributes {
+                    &self.attributes
+                }
+            }
+        )*
+    };
+}
+
+annotated!(
+    Larvie, Casts, Instinct, Param, Enumeration, Member, Union, Variant, Interface, Mixin, Alias, Relation, Index, Module, Import, Lifecycle, State,
+    Transition, Event, View, Selection,
+);
+
+impl Annotated for Constraint {
+    fn doc(&self) -> Option<&str> {
+        match self {
+            Constraint::Key { doc, .. } | Constraint::Unique { doc, .. } => doc.as_deref(),
+        }
+    }
+
+    fn attributes(&self) -> &Attributes {
+        match self {
+            Constraint::Key { attributes, .. } | Constraint::Unique { attributes, .. } => attributes,
+        }
+    }
+}
+
+impl Annotated for Alveolus {
+    fn doc(&self) -> Option<&str> {
+        match self {
+            Alveolus::Larvie(larvie) => larvie.doc(),
+            Alveolus::Enumeration(enumeration) => enumeration.doc(),
+            Alveolus::Union(union) => union.doc(),
+            Alveolus::Interface(interface) => interface.doc(),
+            Alveolus::Mixin(mixin) => mixin.doc(),
+            Alveolus::Alias(alias) => alias.doc(),
+            Alveolus::Module(module) => module.doc(),
+            Alveolus::Lifecycle(lifecycle) => lifecycle.doc(),
+            Alveolus::Event(event) => event.doc(),
+            Alveolus::View(view) => view.doc(),
+        }
+    }
+
+    fn attributes(&self) -> &Attributes {
+        match self {
+            Alveolus::Larvie(larvie) => larvie.attributes(),
+            Alveolus::Enumeration(enumeration) => enumeration.attributes(),
+            Alveolus::Union(union) => union.attributes(),
+            Alveolus::Interface(interface) => interface.attributes(),
+            Alveolus::Mixin(mixin) => mixin.attributes(),
+            Alveolus::Alias(alias) => alias.attributes(),
+            Alveolus::Module(module) => module.attributes(),
+            Alveolus::Lifecycle(lifecycle) => lifecycle.attributes(),
+            Alveolus::Event(event) => event.attributes(),
+            Alveolus::View(view) => view.attributes(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::flora::Flora;
+    use serde_json;
+
+    #[test]
+    fn test_annotated_casts_json_format() {
+        let cast = Casts {
+            doc: Some("Name shown on badges.".to_string()),
+            attributes: Attributes::from([
+                ("ui.hidden".to_string(), Literal::Bool(false)),
+                ("json.rename".to_string(), Literal::Str("fullName".to_string())),
+            ]),
+            ..Casts::new("full_name", Flora::Str)
+        };
+
+        let json = serde_json::to_string(&cast).unwrap();
+
+        assert_eq!(
+            json,
+            "{\"primor\":\"full_name\",\"flora\":\"Str\",\"seals\":[],\"doc\":\"Name shown on badges.\",\
+             \"attributes\":{\"json.rename\":{\"Str\":\"fullName\"},\"ui.hidden\":{\"Bool\":false}}}"
+        );
+        assert_eq!(serde_json::from_str::<Casts>(&json).unwrap(), cast);
+        assert_eq!(cast.doc(), Some("Name shown on badges."));
+        assert_eq!(cast.attribute("json.rename"), Some(&Literal::Str("fullName".to_string())));
+        assert_eq!(cast.attribute("ui.label"), None);
+    }
+
+    #[test]
+    fn test_alveolus_exposes_annotations() {
+        let alveolus = Alveolus::Enumeration(Enumeration {
+            primor: "Color".to_string(),
+            doc: Some("Plumage colors.".to_string()),
+            attributes: Attributes::from([("db.schema".to_string(), Literal::Str("birds".to_string()))]),
+            ..Default::default()
+        });
+
+        assert_eq!(alveolus.doc(), Some("Plumage colors."));
+        assert_eq!(alveolus.attribute("db.schema"), Some(&Literal::Str("birds".to_string())));
+        assert!(Alveolus::Larvie(Larvie::default()).attributes().is_empty());
+    }
+
+    #[test]
+    fn test_annotated_constraint_json_format() {
+        let unique = Constraint::Unique {
+            primor: Some("uq_name".to_string()),
+            casts: vec!["name".to_string()],
+            doc: Some("One bird per name.".to_string()),
+            attributes: Attributes::from([("db.deferrable".to_string(), Literal::Bool(true))]),
+        };
+
+        let json = serde_json::to_string(&unique).unwrap();
+
+        assert_eq!(
+            json,
+            "{\"Unique\":{\"primor\":\"uq_name\",\"casts\":[\"name\"],\"doc\":\"One bird per name.\",\"attributes\":{\"db.deferrable\":{\"Bool\":true}}}}"
+        );
+        assert_eq!(serde_json::from_str::<Constraint>(&json).unwrap(), unique);
+        assert_eq!(unique.doc(), Some("One bird per name."));
+        assert_eq!(unique.attribute("db.deferrable"), Some(&Literal::Bool(true)));
+        assert_eq!(Constraint::key(vec!["id".to_string()]).doc(), None);
+        assert_eq!(Index { doc: Some("Fast lookups.".to_string()), ..Default::default() }.doc(), Some("Fast lookups."));
+        assert!(Import::default().attributes().is_empty());
+    }
+}
@@ -0,0 +1,387 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ir::{IR, flora::Flora, instincts::Param, larvie::Larvie, literal::Literal};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Stmt {
+    Assign { cast: String, value: Expr },
+    If {
+        cond: Expr,
+        then: Vec<Stmt>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        otherwise: Vec<Stmt>,
+    },
+    Return(Option<Expr>),
+    Raise(String),
+    Expr(Expr),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Expr {
+    Literal(Literal),
+    Cast(String),
+    Param(String),
+    Unary { op: UnOp, operand: Box<Expr> },
+    Binary { op: BinOp, lhs: Box<Expr>, rhs: Box<Expr> },
+    Call { instinct: String, args: Vec<Expr> },
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum UnOp {
+    Neg,
+    Not,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum TypeError {
+    Unknown(String),
+    Mismatch(String),
+}
+
+impl fmt::Display for TypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeError::Unknown(message) | TypeError::Mismatch(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+/// What an expression can see: the casts and instincts of its larvie, plus parameters when
+/// it sits inside an instinct body. Aliases in their floras are expanded through `ir`.
+pub struct Scope<'a> {
+    pub ir: &'a IR,
+    pub larvie: &'a Larvie,
+    pub params: &'a [Param],
+}
+
+impl Expr {
+    /// Infers the flora this expression evaluates to.
+    pub fn flora(&self, scope: &Scope) -> Result<Flora, TypeError> {
+        match self {
+            Expr::Literal(Literal::Int(_)) => Ok(Flora::Int),
+            Expr::Literal(Literal::Float(_)) => Ok(Flora::Float),
+            Expr::Literal(Literal::Str(_)) => Ok(Flora::Str),
+            Expr::Literal(Literal::Bool(_)) => Ok(Flora::Bool),
+            Expr::Literal(Literal::Null) => Err(TypeError::Mismatch("Null is only allowed where an Optional is expected".to_string())),
+            Expr::Cast(name) => scope
+                .larvie
+                .cast(name)
+                .map(|cast| scope.ir.underlying(&cast.flora))
+                .ok_or_else(|| TypeError::Unknown(format!("larvie '{}' has no cast '{}'", scope.larvie.primor, name))),
+            Expr::Param(name) => scope
+                .params
+                .iter()
+                .find(|param| &param.primor == name)
+                .map(|param| scope.ir.underlying(&param.flora))
+                .ok_or_else(|| TypeError::Unknown(format!("unknown parameter '{}'", name))),
+            Expr::Unary { op: UnOp::Neg, operand } => {
+                let flora = operand.flora(scope)?;
+                if flora.is_numeric() {
+                    Ok(flora)
+                } else {
+                    Err(TypeError::Mismatch(format!("cannot negate {:?}", flora)))
+                }
+            }
+            Expr::Unary { op: UnOp::Not, operand } => {
+                operand.expect(&Flora::Bool, scope)?;
+                Ok(Flora::Bool)
+            }
+            Expr::Binary { op, lhs, rhs } => binary(*op, lhs, rhs, scope),
+            Expr::Call { instinct, args } => match call(instinct, args, scope)? {
+                Some(flora) => Ok(flora),
+                None => Err(TypeError::Mismatch(format!("instinct '{}' returns nothing", instinct))),
+            },
+        }
+    }
+
+    /// Checks that this expression can be stored where `target` is expected.
+    pub fn expect(&self, target: &Flora, scope: &Scope) -> Result<(), TypeError> {
+        if let Expr::Literal(literal) = self {
+            return if literal.fits(target) {
+                Ok(())
+            } else {
+                Err(TypeError::Mismatch(format!("{:?} does not fit {:?}", literal, target)))
+            };
+        }
+
+        let flora = self.flora(scope)?;
+        if assignable(&flora, target) {
+            Ok(())
+        } else {
+            Err(TypeError::Mismatch(format!("expected {:?}, found {:?}", target, flora)))
+        }
+    }
+
+    /// Names of every cast this expression reads.
+    pub fn casts(&self) -> Vec<&str> {
+        match self {
+            Expr::Cast(name) => vec![name.as_str()],
+            Expr::Literal(_) | Expr::Param(_) => vec![],
+            Expr::Unary { operand, .. } => operand.casts(),
+            Expr::Binary { lhs, rhs, .. } => {
+                let mut casts = lhs.casts();
+                casts.extend(rhs.casts());
+                casts
+            }
+            Expr::Call { args, .. } => args.iter().flat_map(Expr::casts).collect(),
+        }
+    }
+}
+
+/// Checks a call's arguments and returns what the called instinct returns.
+pub fn call(instinct: &str, args: &[Expr], scope: &Scope) -> Result<Option<Flora>, TypeError> {
+    let Some(callee) = scope.larvie.instincts.iter().find(|i| i.echo == instinct) else {
+        return Err(TypeError::Unknown(format!("larvie '{}' has no instinct '{}'", scope.larvie.primor, instinct)));
+    };
+    if args.len() != callee.params.len() {
+        return Err(TypeError::Mismatch(format!(
+            "instinct '{}' takes {} arguments, found {}",
+            instinct,
+            callee.params.len(),
+            args.len()
+        )));
+    }
+    for (arg, param) in args.iter().zip(&callee.params) {
+        arg.expect(&scope.ir.underlying(&param.flora), scope)?;
+    }
+    Ok(callee.returns.as_ref().map(|returns| scope.ir.underlying(returns)))
+}
+
+/// `value` can be stored in `target` as is, or wrapped into an `Optional`.
+pub fn assignable(value: &Flora, target: &Flora) -> bool {
+    value == target || matches!(target, Flora::Optional(inner) if assignable(value, inner))
+}
+
+fn binary(op: BinOp, lhs: &Expr, rhs: &Expr, scope: &Scope) -> Result<Flora, TypeError> {
+    if matches!(op, BinOp::And | BinOp::Or) {
+        lhs.expect(&Flora::Bool, scope)?;
+        rhs.expect(&Flora::Bool, scope)?;
+        return Ok(Flora::Bool);
+    }
+
+    if matches!(op, BinOp::Eq | BinOp::Ne) {
+        let null = Expr::Literal(Literal::Null);
+        if *lhs == null || *rhs == null {
+            let other = if *lhs == null { rhs } else { lhs };
+            let flora = if *other == null { return Ok(Flora::Bool) } else { other.flora(scope)? };
+            return match flora {
+                Flora::Optional(_) => Ok(Flora::Bool),
+                flora => Err(TypeError::Mismatch(format!("{:?} is never Null", flora))),
+            };
+        }
+    }
+
+    // A literal operand adopts the flora of the other side, so `energy + 1` works for any integer width.
+    let flora = match (lhs, rhs) {
+        (Expr::Literal(_), Expr::Literal(_)) => {
+            let flora = lhs.flora(scope)?;
+            rhs.expect(&flora, scope)?;
+            flora
+        }
+        (Expr::Literal(_), other) | (other, Expr::Literal(_)) => {
+            let flora = other.flora(scope)?;
+            let literal = if matches!(lhs, Expr::Literal(_)) { lhs } else { rhs };
+            literal.expect(&flora, scope)?;
+            flora
+        }
+        _ => {
+            let (left, right) = (lhs.flora(scope)?, rhs.flora(scope)?);
+            if left != right {
+                return Err(TypeError::Mismatch(format!("cannot apply {:?} to {:?} and {:?}", op, left, right)));
+            }
+            left
+        }
+    };
+
+    let ordered = flora.is_numeric() || matches!(flora, Flora::Str | Flora::Date | Flora::DateTime | Flora::Duration);
+    match op {
+        BinOp::Add if flora.is_numeric() || flora == Flora::Str || flora == Flora::Duration => Ok(flora),
+        BinOp::Sub | BinOp::Mul | BinOp::Div | BinOp::Rem if flora.is_numeric() => Ok(flora),
+        BinOp::Eq | BinOp::Ne => Ok(Flora::Bool),
+        BinOp::Lt | BinOp::Le | BinOp::Gt | BinOp::Ge if ordered => Ok(Flora::Bool),
+        _ => Err(TypeError::Mismatch(format!("cannot apply {:?} to {:?}", op, flora))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{alias::Alias, alveolus::Alveolus, casts::Casts, instincts::Instinct};
+    use serde_json;
+
+    fn bird() -> Larvie {
+        let cast = |primor: &str, flora| Casts::new(primor, flora);
+        Larvie {
+            primor: "Bird".to_string(),
+            casts: vec![
+                cast("energy", Flora::U16),
+                cast("name", Flora::Str),
+                cast("nickname", Flora::Optional(Box::new(Flora::Str))),
+                cast("alive", Flora::Bool),
+            ],
+            instincts: vec![
+                Instinct {
+                    echo: "rest".to_string(),
+                    params: vec![Param::new("hours", Flora::U16)],
+                    returns: Some(Flora::U16),
+                    ..Default::default()
+                },
+                Instinct { echo: "sing".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        }
+    }
+
+    fn int(value: i64) -> Box<Expr> {
+        Box::new(Expr::Literal(Literal::Int(value)))
+    }
+
+    fn cast(name: &str) -> Box<Expr> {
+        Box::new(Expr::Cast(name.to_string()))
+    }
+
+    #[test]
+    fn test_infer_arithmetic_with_literal() {
+        let ir = IR { alveolus: vec![] };
+        let larvie = bird();
+        let scope = Scope { ir: &ir, larvie: &larvie, params: &[] };
+
+        let expr = Expr::Binary { op: BinOp::Add, lhs: cast("energy"), rhs: int(10) };
+
+        assert_eq!(expr.flora(&scope), Ok(Flora::U16));
+        assert!(expr.expect(&Flora::U16, &scope).is_ok());
+        assert!(expr.expect(&Flora::Optional(Box::new(Flora::U16)), &scope).is_ok());
+        assert!(matches!(expr.expect(&Flora::Str, &scope), Err(TypeError::Mismatch(_))));
+    }
+
+    #[test]
+    fn test_infer_literal_out_of_range() {
+        let ir = IR { alveolus: vec![] };
+        let larvie = bird();
+        let scope = Scope { ir: &ir, larvie: &larvie, params: &[] };
+
+        let expr = Expr::Binary { op: BinOp::Sub, lhs: cast("energy"), rhs: int(-1) };
+
+        assert!(matches!(expr.flora(&scope), Err(TypeError::Mismatch(_))));
+    }
+
+    #[test]
+    fn test_infer_comparisons_and_logic() {
+        let ir = IR { alveolus: vec![] };
+        let larvie = bird();
+        let scope = Scope { ir: &ir, larvie: &larvie, params: &[] };
+
+        let low = Expr::Binary { op: BinOp::Lt, lhs: cast("energy"), rhs: int(5) };
+        let expr = Expr::Binary {
+            op: BinOp::And,
+            lhs: Box::new(low),
+            rhs: Box::new(Expr::Unary { op: UnOp::Not, operand: cast("alive") }),
+        };
+
+        assert_eq!(expr.flora(&scope), Ok(Flora::Bool));
+
+        let mixed = Expr::Binary { op: BinOp::Eq, lhs: cast("energy"), rhs: cast("name") };
+        assert!(matches!(mixed.flora(&scope), Err(TypeError::Mismatch(_))));
+    }
+
+    #[test]
+    fn test_null_comparisons() {
+        let ir = IR { alveolus: vec![] };
+        let larvie = bird();
+        let scope = Scope { ir: &ir, larvie: &larvie, params: &[] };
+        let null = Box::new(Expr::Literal(Literal::Null));
+
+        let optional = Expr::Binary { op: BinOp::Eq, lhs: cast("nickname"), rhs: null.clone() };
+        let required = Expr::Binary { op: BinOp::Ne, lhs: null, rhs: cast("name") };
+
+        assert_eq!(optional.flora(&scope), Ok(Flora::Bool));
+        assert!(matches!(required.flora(&scope), Err(TypeError::Mismatch(_))));
+    }
+
+    #[test]
+    fn test_infer_params_and_calls() {
+        let ir = IR { alveolus: vec![] };
+        let larvie = bird();
+        let params = [Param::new("bonus", Flora::U16)];
+        let scope = Scope { ir: &ir, larvie: &larvie, params: &params };
+
+        let rest = Expr::Call { instinct: "rest".to_string(), args: vec![Expr::Param("bonus".to_string())] };
+        assert_eq!(rest.flora(&scope), Ok(Flora::U16));
+
+        let sing = Expr::Call { instinct: "sing".to_string(), args: vec![] };
+        assert!(matches!(sing.flora(&scope), Err(TypeError::Mismatch(_))));
+        assert_eq!(call("sing", &[], &scope), Ok(None));
+
+        let arity = Expr::Call { instinct: "rest".to_string(), args: vec![] };
+        assert!(matches!(arity.flora(&scope), Err(TypeError::Mismatch(_))));
+
+        assert!(matches!(Expr::Param("missing".to_string()).flora(&scope), Err(TypeError::Unknown(_))));
+        assert!(matches!(Expr::Cast("wings".to_string()).flora(&scope), Err(TypeError::Unknown(_))));
+        assert!(matches!(call("fly", &[], &scope), Err(TypeError::Unknown(_))));
+    }
+
+    #[test]
+    fn test_infer_through_aliases() {
+        let ir = IR {
+            alveolus: vec![Alveolus::Alias(Alias::new("Energy", Flora::U16))],
+        };
+        let mut larvie = bird();
+        larvie.casts[0].flora = Flora::Bug("Energy".to_string());
+        let params = [Param::new("bonus", Flora::Bug("Energy".to_string()))];
+        let scope = Scope { ir: &ir, larvie: &larvie, params: &params };
+
+        let expr = Expr::Binary { op: BinOp::Add, lhs: cast("energy"), rhs: Box::new(Expr::Param("bonus".to_string())) };
+
+        assert_eq!(expr.flora(&scope), Ok(Flora::U16));
+        assert!(Expr::Literal(Literal::Int(10)).expect(&ir.underlying(&larvie.casts[0].flora), &scope).is_ok());
+    }
+
+    #[test]
+    fn test_expr_casts() {
+        let expr = Expr::Binary {
+            op: BinOp::Add,
+            lhs: cast("energy"),
+            rhs: Box::new(Expr::Call { instinct: "rest".to_string(), args: vec![Expr::Cast("name".to_string())] }),
+        };
+
+        assert_eq!(expr.casts(), vec!["energy", "name"]);
+    }
+
+    #[test]
+    fn test_body_json_format() {
+        let body = vec![
+            Stmt::If {
+                cond: Expr::Binary { op: BinOp::Lt, lhs: cast("energy"), rhs: int(5) },
+                then: vec![Stmt::Raise("Tired".to_string())],
+                otherwise: vec![],
+            },
+            Stmt::Assign { cast: "energy".to_string(), value: Expr::Binary { op: BinOp::Sub, lhs: cast("energy"), rhs: int(5) } },
+            Stmt::Return(None),
+        ];
+
+        let json = serde_json::to_string(&body).unwrap();
+
+        assert!(json.starts_with("[{\"If\":{\"cond\":{\"Binary\":{\"op\":\"Lt\",\"lhs\":{\"Cast\":\"energy\"},\"rhs\":{\"Literal\":{\"Int\":5}}}}"));
+        assert!(json.ends_with("{\"Return\":null}]"));
+        assert_eq!(serde_json::from_str::<Vec<Stmt>>(&json).unwrap(), body);
+    }
+}
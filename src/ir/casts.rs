@@ -1,12 +1,50 @@
 use serde::{Deserialize, Serialize};
+use crate::ir::attribute::Attributes;
+use crate::ir::body::Expr;
 use crate::ir::flora::Flora;
 use crate::ir::seal::Seal;
+use crate::ir::span::Span;
+use crate::dsl::tree::Gene;
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Casts {
     pub primor: String,
     pub flora: Flora,
     pub seals: Vec<Seal>,
+    /// Derives the value from sibling casts; a computed cast is never stored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub computed: Option<Expr>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+impl Casts {
+    /// A stored cast with no seals. A cast always has a flora, so there is no `Default`;
+    /// fill in the other fields with `Casts { seals, ..Casts::new(primor, flora) }`.
+    pub fn new(primor: impl Into<String>, flora: Flora) -> Casts {
+        Casts { primor: primor.into(), flora, seals: vec![], computed: None, doc: None, attributes: Attributes::new(), span: None }
+    }
+
+    /// Whether storage backends persist this cast; computed casts become getters instead.
+    pub fn is_stored(&self) -> bool {
+        self.computed.is_none()
+    }
+}
+
+/// Genes are required unless their specie is `Optional<…>`.
+impl From<Gene> for Casts {
+    fn from(gene: Gene) -> Self {
+        let flora: Flora = gene.specie.into();
+        Casts {
+            seals: if matches!(flora, Flora::Optional(_)) { vec![] } else { vec![Seal::Vital] },
+            span: Some(gene.span),
+            ..Casts::new(gene.name, flora)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -17,9 +55,8 @@ mod tests {
     #[test]
     fn test_casts_creation() {
         let cast = Casts {
-            primor: "test_field".to_string(),
-            flora: Flora::Int,
             seals: vec![Seal::Vital],
+            ..Casts::new("test_field", Flora::Int)
         };
 
         assert_eq!(cast.primor, "test_field");
@@ -31,9 +68,8 @@ mod tests {
     #[test]
     fn test_casts_with_multiple_seals() {
         let cast = Casts {
-            primor: "complex_field".to_string(),
-            flora: Flora::Str,
             seals: vec![Seal::Vital, Seal::Core, Seal::Root],
+            ..Casts::new("complex_field", Flora::Str)
         };
 
         assert_eq!(cast.seals.len(), 3);
@@ -45,9 +81,8 @@ mod tests {
     #[test]
     fn test_casts_with_no_seals() {
         let cast = Casts {
-            primor: "optional_field".to_string(),
-            flora: Flora::Bool,
             seals: vec![],
+            ..Casts::new("optional_field", Flora::Bool)
         };
 
         assert_eq!(cast.seals.len(), 0);
@@ -56,9 +91,8 @@ mod tests {
     #[test]
     fn test_casts_serialization() {
         let cast = Casts {
-            primor: "serializable_field".to_string(),
-            flora: Flora::Bug("Bee".to_string()),
             seals: vec![Seal::Core],
+            ..Casts::new("serializable_field", Flora::Bug("Bee".to_string()))
         };
 
         let json = serde_json::to_string(&cast).unwrap();
@@ -75,9 +109,8 @@ mod tests {
 
         for flora in flora_types {
             let cast = Casts {
-                primor: format!("field_{:?}", flora),
-                flora: flora.clone(),
                 seals: vec![Seal::Vital],
+                ..Casts::new(format!("field_{:?}", flora), flora.clone())
             };
 
             assert_eq!(cast.flora, flora);
@@ -87,21 +120,18 @@ mod tests {
     #[test]
     fn test_casts_equality() {
         let cast1 = Casts {
-            primor: "same_field".to_string(),
-            flora: Flora::Int,
             seals: vec![Seal::Vital],
+            ..Casts::new("same_field", Flora::Int)
         };
 
         let cast2 = Casts {
-            primor: "same_field".to_string(),
-            flora: Flora::Int,
             seals: vec![Seal::Vital],
+            ..Casts::new("same_field", Flora::Int)
         };
 
         let cast3 = Casts {
-            primor: "different_field".to_string(),
-            flora: Flora::Int,
             seals: vec![Seal::Vital],
+            ..Casts::new("different_field", Flora::Int)
         };
 
         assert_eq!(cast1, cast2);
@@ -111,9 +141,8 @@ mod tests {
     #[test]
     fn test_casts_clone() {
         let original = Casts {
-            primor: "original".to_string(),
-            flora: Flora::Str,
             seals: vec![Seal::Core, Seal::Root],
+            ..Casts::new("original", Flora::Str)
         };
 
         let cloned = original.clone();
@@ -122,4 +151,30 @@ mod tests {
         // Ensure deep clone
         assert_eq!(original.seals.len(), cloned.seals.len());
     }
+
+    #[test]
+    fn test_computed_cast() {
+        let age = Casts {
+            computed: Some(Expr::Cast("years".to_string())),
+            ..Casts::new("age", Flora::Int)
+        };
+
+        let json = serde_json::to_string(&age).unwrap();
+
+        assert!(!age.is_stored());
+        assert_eq!(json, "{\"primor\":\"age\",\"flora\":\"Int\",\"seals\":[],\"computed\":{\"Cast\":\"years\"}}");
+        assert_eq!(serde_json::from_str::<Casts>(&json).unwrap(), age);
+        assert!(Casts { computed: None, ..age }.is_stored());
+    }
+
+    #[test]
+    fn test_casts_from_gene() {
+        use crate::dsl::tree::Specie;
+
+        let gene = |name: &str, specie| Gene { name: name.to_string(), specie, span: Span { file: None, start: 0, end: 0, line: 1, col: 1 } };
+
+        assert_eq!(Casts::from(gene("energy", Specie::Int)).seals, vec![Seal::Vital]);
+        assert_eq!(Casts::from(gene("nick", Specie::Optional(Box::new(Specie::Str)))).seals, vec![]);
+        assert_eq!(Casts::from(gene("tags", Specie::List(Box::new(Specie::Str)))).seals, vec![Seal::Vital]);
+    }
 }
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ir::attribute::Attributes;
+
+/// Table-level constraints spanning one or more casts of a `Larvie`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Constraint {
+    Key {              // composite PrimaryKey
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        primor: Option<String>,
+        casts: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        doc: Option<String>,
+        #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+        attributes: Attributes,
+    },
+    Unique {           // composite Unique
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        primor: Option<String>,
+        casts: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        doc: Option<String>,
+        #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+        attributes: Attributes,
+    },
+}
+
+impl Constraint {
+    /// An unnamed key over `casts`.
+    pub fn key(casts: Vec<String>) -> Constraint {
+        Constraint::Key { primor: None, casts, doc: None, attributes: Attributes::new() }
+    }
+
+    /// An unnamed unique constraint over `casts`.
+    pub fn unique(casts: Vec<String>) -> Constraint {
+        Constraint::Unique { primor: None, casts, doc: None, attributes: Attributes::new() }
+    }
+
+    pub fn primor(&self) -> Option<&str> {
+        match self {
+            Constraint::Key { primor, .. } | Constraint::Unique { primor, .. } => primor.as_deref(),
+        }
+    }
+
+    pub fn casts(&self) -> &[String] {
+        match self {
+            Constraint::Key { casts, .. } | Constraint::Unique { casts, .. } => casts,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Index {
+    pub primor: String,
+    pub parts: Vec<IndexPart>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct IndexPart {
+    pub cast: String,
+    #[serde(default)]
+    pub order: Order,
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Order {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_constraint_accessors() {
+        let key = Constraint::key(vec!["hive".to_string(), "slot".to_string()]);
+        let unique = Constraint::Unique {
+            primor: Some("uq_name".to_string()),
+            casts: vec!["name".to_string()],
+            doc: None,
+            attributes: Attributes::new(),
+        };
+
+        assert_eq!(key.primor(), None);
+        assert_eq!(key.casts(), ["hive", "slot"]);
+        assert_eq!(unique.primor(), Some("uq_name"));
+    }
+
+    #[test]
+    fn test_constraint_json_format() {
+        let key = Constraint::key(vec!["hive".to_string(), "slot".to_string()]);
+
+        let json = serde_json::to_string(&key).unwrap();
+
+        assert_eq!(json, "{\"Key\":{\"casts\":[\"hive\",\"slot\"]}}");
+        assert_eq!(serde_json::from_str::<Constraint>(&json).unwrap(), key);
+    }
+
+    #[test]
+    fn test_index_order_defaults_to_asc() {
+        let index: Index = serde_json::from_str(
+            "{\"primor\":\"by_energy\",\"parts\":[{\"cast\":\"energy\",\"order\":\"Desc\"},{\"cast\":\"name\"}]}"
+        ).unwrap();
+
+        assert_eq!(index.parts[0].order, Order::Desc);
+        assert_eq!(index.parts[1].order, Order::Asc);
+    }
+}
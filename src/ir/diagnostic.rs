@@ -0,0 +1,204 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ir::span::Span;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Code {
+    DuplicateLarvie,
+    DuplicateCast,
+    DuplicateInstinct,
+    DuplicateSeal,
+    UnknownBug,
+    MultipleCore,
+    InvalidMapKey,
+    InvalidDecimal,
+    SealMismatch,
+    InvalidSeal,
+    ConflictingSeals,
+    UnknownReference,
+    InvalidReference,
+    UnknownCast,
+    InvalidConstraint,
+    DuplicateIndex,
+    DuplicateParam,
+    DuplicateFault,
+    UnknownSymbol,
+    TypeMismatch,
+    UnknownFault,
+    DuplicateEnumeration,
+    DuplicateMember,
+    InvalidMember,
+    DuplicateUnion,
+    DuplicateVariant,
+    InvalidVariant,
+    DuplicateInterface,
+    UnknownInterface,
+    InvalidInterface,
+    MissingInstinct,
+    SignatureMismatch,
+    DuplicateMixin,
+    UnknownParent,
+    UnknownMixin,
+    InvalidMixin,
+    InheritanceCycle,
+    ConflictingCast,
+    InvalidGeneric,
+    UnknownTypeParam,
+    DuplicateAlias,
+    AliasCycle,
+    DuplicateRelation,
+    InvalidRelation,
+    InvalidComputed,
+    DuplicateModule,
+    InvalidModule,
+    UnknownImport,
+    PrivateName,
+    UnknownPrivate,
+    DuplicateLifecycle,
+    InvalidLifecycle,
+    DuplicateState,
+    UnknownState,
+    UnknownInstinct,
+    UnreachableState,
+    DeadState,
+    DuplicateEvent,
+    UnknownEvent,
+    DuplicateView,
+    InvalidView,
+    UnresolvedName,
+    AmbiguousName,
+    EmbeddingCycle,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Location of a node inside an `IR`, e.g. `alveolus[0].casts[2]`.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Path(pub Vec<Segment>);
+
+impl Path {
+    pub fn root() -> Path {
+        Path(vec![])
+    }
+
+    pub fn key(&self, key: &str) -> Path {
+        let mut segments = self.0.clone();
+        segments.push(Segment::Key(key.to_string()));
+        Path(segments)
+    }
+
+    pub fn index(&self, index: usize) -> Path {
+        let mut segments = self.0.clone();
+        segments.push(Segment::Index(index));
+        Path(segments)
+    }
+
+    pub fn at(&self, key: &str, index: usize) -> Path {
+        self.key(key).index(index)
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                Segment::Key(key) if i == 0 => write!(f, "{}", key)?,
+                Segment::Key(key) => write!(f, ".{}", key)?,
+                Segment::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub code: Code,
+    pub severity: Severity,
+    pub path: Path,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+impl Diagnostic {
+    pub fn error(code: Code, path: Path, message: impl Into<String>) -> Diagnostic {
+        Diagnostic { code, severity: Severity::Error, path, message: message.into(), span: None }
+    }
+
+    pub fn warning(code: Code, path: Path, message: impl Into<String>) -> Diagnostic {
+        Diagnostic { code, severity: Severity::Warning, path, message: message.into(), span: None }
+    }
+
+    /// Attaches the source location of the offending node, when it has one.
+    pub fn with_span(mut self, span: Option<&Span>) -> Diagnostic {
+        self.span = span.cloned();
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        match &self.span {
+            Some(span) => write!(f, "{}[{:?}] {} ({}): {}", severity, self.code, self.path, span, self.message),
+            None => write!(f, "{}[{:?}] {}: {}", severity, self.code, self.path, self.message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_path_display() {
+        let path = Path::root().at("alveolus", 0).at("casts", 2).key("flora");
+
+        assert_eq!(path.to_string(), "alveolus[0].casts[2].flora");
+        assert_eq!(Path::root().to_string(), "");
+    }
+
+    #[test]
+    fn test_diagnostic_display() {
+        let diagnostic = Diagnostic::error(Code::DuplicateCast, Path::root().at("alveolus", 1), "duplicate cast 'id'");
+
+        assert_eq!(diagnostic.to_string(), "error[DuplicateCast] alveolus[1]: duplicate cast 'id'");
+        assert!(diagnostic.is_error());
+
+        let span = Span { file: Some("bird.sc".to_string()), start: 10, end: 20, line: 3, col: 5 };
+        let diagnostic = diagnostic.with_span(Some(&span));
+        assert_eq!(diagnostic.to_string(), "error[DuplicateCast] alveolus[1] (bird.sc:3:5): duplicate cast 'id'");
+    }
+
+    #[test]
+    fn test_diagnostic_serialization() {
+        let diagnostic = Diagnostic::warning(Code::DuplicateSeal, Path::root().at("alveolus", 0), "repeated seal");
+
+        let json = serde_json::to_string(&diagnostic).unwrap();
+        let deserialized: Diagnostic = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, diagnostic);
+        assert!(!deserialized.is_error());
+    }
+}
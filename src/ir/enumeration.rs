@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ir::{attribute::Attributes, literal::Literal, span::Span};
+
+/// A closed set of named members, referenced from casts through `Flora::Bug`.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Enumeration {
+    pub primor: String,
+    pub members: Vec<Member>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Member {
+    pub primor: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Literal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+impl Enumeration {
+    pub fn member(&self, primor: &str) -> Option<&Member> {
+        self.members.iter().find(|member| member.primor == primor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    fn color() -> Enumeration {
+        let member = |primor: &str, value| Member { primor: primor.to_string(), value, ..Default::default() };
+        Enumeration {
+            primor: "Color".to_string(),
+            members: vec![member("Red", Some(Literal::Int(1))), member("Green", None), member("Blue", None)],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_enumeration_member_lookup() {
+        let color = color();
+
+        assert_eq!(color.member("Red").unwrap().value, Some(Literal::Int(1)));
+        assert!(color.member("Green").is_some());
+        assert!(color.member("Purple").is_none());
+    }
+
+    #[test]
+    fn test_enumeration_json_format() {
+        let color = color();
+
+        let json = serde_json::to_string(&color).unwrap();
+
+        assert_eq!(
+            json,
+            "{\"primor\":\"Color\",\"members\":[{\"primor\":\"Red\",\"value\":{\"Int\":1}},{\"primor\":\"Green\"},{\"primor\":\"Blue\"}]}"
+        );
+        assert_eq!(serde_json::from_str::<Enumeration>(&json).unwrap(), color);
+    }
+}
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ir::{IR, alveolus::Alveolus, attribute::Attributes, casts::Casts, instincts::Instinct, larvie::Larvie, span::Span};
+
+/// A domain event with a typed payload, raised by the instincts that list it in `emits`.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Event {
+    pub primor: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub casts: Vec<Casts>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+impl Event {
+    pub fn cast(&self, primor: &str) -> Option<&Casts> {
+        self.casts.iter().find(|cast| cast.primor == primor)
+    }
+}
+
+impl IR {
+    pub fn event(&self, name: &str) -> Option<&Event> {
+        self.alveolus.iter().find_map(|alveolus| match alveolus {
+            Alveolus::Event(event) if event.primor == name => Some(event),
+            _ => None,
+        })
+    }
+
+    /// The larvie instincts that emit the event named `event`, for wiring up its handlers.
+    pub fn emitters(&self, event: &str) -> Vec<(&Larvie, &Instinct)> {
+        let mut emitters = Vec::new();
+        for alveolus in &self.alveolus {
+            if let Alveolus::Larvie(larvie) = alveolus {
+                for instinct in larvie.instincts.iter().filter(|instinct| instinct.emits.iter().any(|name| name == event)) {
+                    emitters.push((larvie, instinct));
+                }
+            }
+        }
+        emitters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{flora::Flora, seal::Seal};
+    use serde_json;
+
+    fn hatched() -> Event {
+        Event {
+            primor: "Hatched".to_string(),
+            casts: vec![Casts { seals: vec![Seal::Vital], ..Casts::new("at", Flora::DateTime) }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_event_json_format() {
+        let event = hatched();
+
+        let json = serde_json::to_string(&Alveolus::Event(event.clone())).unwrap();
+
+        assert_eq!(json, "{\"Event\":{\"primor\":\"Hatched\",\"casts\":[{\"primor\":\"at\",\"flora\":\"DateTime\",\"seals\":[\"Vital\"]}]}}");
+        assert_eq!(serde_json::from_str::<Alveolus>(&json).unwrap(), Alveolus::Event(event));
+        assert_eq!(serde_json::to_string(&Event { primor: "Tick".to_string(), ..Default::default() }).unwrap(), "{\"primor\":\"Tick\"}");
+    }
+
+    #[test]
+    fn test_event_emitters() {
+        let emitting = |echo: &str, emits: &[&str]| Instinct {
+            echo: echo.to_string(),
+            emits: emits.iter().map(|name| name.to_string()).collect(),
+            ..Default::default()
+        };
+        let ir = IR {
+            alveolus: vec![
+                Alveolus::Event(hatched()),
+                Alveolus::Larvie(Larvie {
+                    primor: "Egg".to_string(),
+                    instincts: vec![emitting("hatch", &["Hatched"]), emitting("crack", &["Cracked", "Hatched"]), emitting("warm", &[])],
+                    ..Default::default()
+                }),
+            ],
+        };
+
+        let emitters: Vec<&str> = ir.emitters("Hatched").iter().map(|(_, instinct)| instinct.echo.as_str()).collect();
+
+        assert_eq!(emitters, vec!["hatch", "crack"]);
+        assert_eq!(ir.event("Hatched").unwrap().cast("at").unwrap().flora, Flora::DateTime);
+        assert!(ir.event("Cracked").is_none());
+    }
+}
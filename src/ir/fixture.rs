@@ -0,0 +1,15 @@
+//! Builders shared by the unit tests of the IR modules.
+
+use crate::ir::{alveolus::Alveolus, casts::Casts, flora::Flora, larvie::Larvie, seal::Seal};
+
+pub fn larvie(primor: &str, casts: Vec<Casts>) -> Alveolus {
+    Alveolus::Larvie(Larvie { primor: primor.to_string(), casts, ..Default::default() })
+}
+
+pub fn cast(primor: &str, flora: Flora, seals: Vec<Seal>) -> Casts {
+    Casts { seals, ..Casts::new(primor, flora) }
+}
+
+pub fn bug(name: &str) -> Flora {
+    Flora::Bug(name.to_string())
+}
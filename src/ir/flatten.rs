@@ -0,0 +1,326 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::ir::{
+    IR,
+    alveolus::Alveolus,
+    casts::Casts,
+    diagnostic::{Code, Diagnostic, Path},
+    larvie::Larvie,
+    symbol::{Handle, SymbolTable},
+};
+
+/// Larvies already expanded, and the ones being expanded to catch `extends` cycles.
+struct Flattener<'a> {
+    ir: &'a IR,
+    symbols: SymbolTable<'a>,
+    done: HashMap<usize, Larvie>,
+    stack: Vec<usize>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl IR {
+    /// Expands `extends` and `mixins` into self-contained larvies and drops the mixins.
+    ///
+    /// Inherited casts come first, then each mixin in order, then the larvie's own; a later
+    /// cast replaces an earlier one of the same name only if both share the same flora.
+    /// Run `validate` on the result, where every cast and instinct is in place.
+    pub fn flatten(&self) -> Result<IR, Vec<Diagnostic>> {
+        let mut flattener = Flattener { ir: self, symbols: self.symbols(), done: HashMap::new(), stack: Vec::new(), diagnostics: Vec::new() };
+
+        let mut alveolus = Vec::new();
+        for (i, item) in self.alveolus.iter().enumerate() {
+            match item {
+                Alveolus::Larvie(_) => alveolus.push(Alveolus::Larvie(flattener.larvie(i))),
+                Alveolus::Mixin(_) => {}
+                other => alveolus.push(other.clone()),
+            }
+        }
+
+        if flattener.diagnostics.is_empty() {
+            Ok(IR { alveolus })
+        } else {
+            Err(flattener.diagnostics)
+        }
+    }
+}
+
+impl Flattener<'_> {
+    fn larvie(&mut self, i: usize) -> Larvie {
+        if let Some(larvie) = self.done.get(&i) {
+            return larvie.clone();
+        }
+        let Alveolus::Larvie(own) = &self.ir.alveolus[i] else { unreachable!("only larvies are flattened") };
+        let path = Path::root().at("alveolus", i);
+
+        if self.stack.contains(&i) {
+            self.diagnostics.push(Diagnostic::error(
+                Code::InheritanceCycle,
+                path.key("extends"),
+                format!("larvie '{}' extends itself", own.primor),
+            ).with_span(own.span.as_ref()));
+            return Larvie::default();
+        }
+        self.stack.push(i);
+
+        let mut flat = match &own.extends {
+            Some(parent) => match self.symbols.first(parent) {
+                Some(Handle(j)) if matches!(self.ir.alveolus[j], Alveolus::Larvie(_)) => self.larvie(j),
+                _ => {
+                    self.diagnostics.push(Diagnostic::error(
+                        Code::UnknownParent,
+                        path.key("extends"),
+                        format!("larvie '{}' extends unknown larvie '{}'", own.primor, parent),
+                    ).with_span(own.span.as_ref()));
+                    Larvie::default()
+                }
+            },
+            None => Larvie::default(),
+        };
+        flat.primor = own.primor.clone();
+        flat.span = own.span.clone();
+        // Type parameters are scoped to the larvie that declares them.
+        flat.generics = own.generics.clone();
+        // Docs and attributes describe the larvie itself and are not inherited.
+        flat.doc = own.doc.clone();
+        flat.attributes = own.attributes.clone();
+
+        for (k, name) in own.mixins.iter().enumerate() {
+            match self.symbols.first(name).map(|handle| self.symbols.get(handle)) {
+                Some(Alveolus::Mixin(mixin)) => self.merge(&mut flat, &mixin.casts, ("mixin", &mixin.primor), &path.at("mixins", k)),
+                _ => self.diagnostics.push(Diagnostic::error(
+                    Code::UnknownMixin,
+                    path.at("mixins", k),
+                    format!("larvie '{}' includes unknown mixin '{}'", own.primor, name),
+                ).with_span(own.span.as_ref())),
+            }
+        }
+        self.merge(&mut flat, &own.casts, ("larvie", &own.primor), &path.key("casts"));
+
+        for instinct in &own.instincts {
+            match flat.instincts.iter_mut().find(|inherited| inherited.echo == instinct.echo) {
+                Some(inherited) => {
+                    if !inherited.same_signature(instinct) {
+                        self.diagnostics.push(Diagnostic::error(
+                            Code::SignatureMismatch,
+                            path.key("instincts"),
+                            format!("instinct '{}' of larvie '{}' does not match the inherited signature", instinct.echo, own.primor),
+                        ).with_span(instinct.span.as_ref()));
+                    }
+                    *inherited = instinct.clone();
+                }
+                None => flat.instincts.push(instinct.clone()),
+            }
+        }
+
+        flat.relations.extend(own.relations.iter().cloned());
+        flat.constraints.extend(own.constraints.iter().cloned());
+        flat.indexes.extend(own.indexes.iter().cloned());
+        for name in &own.implements {
+            if !flat.implements.contains(name) {
+                flat.implements.push(name.clone());
+            }
+        }
+        flat.extends = None;
+        flat.mixins = Vec::new();
+
+        self.stack.pop();
+        self.done.insert(i, flat.clone());
+        flat
+    }
+
+    /// Adds `casts` from `origin`, a kind and a name, to `flat`, replacing same-named casts of the
+    /// same flora that came from a parent or an earlier mixin. A name repeated within `origin`
+    /// itself is a duplicate, not an override.
+    fn merge(&mut self, flat: &mut Larvie, casts: &[Casts], (kind, origin): (&str, &str), path: &Path) {
+        let mut seen = HashSet::new();
+        for cast in casts {
+            if !seen.insert(cast.primor.as_str()) {
+                self.diagnostics.push(Diagnostic::error(
+                    Code::DuplicateCast,
+                    path.clone(),
+                    format!("cast '{}' is declared more than once in {} '{}'", cast.primor, kind, origin),
+                ).with_span(cast.span.as_ref()));
+                continue;
+            }
+            match flat.casts.iter_mut().find(|existing| existing.primor == cast.primor) {
+                Some(existing) if existing.flora != cast.flora => {
+                    self.diagnostics.push(Diagnostic::error(
+                        Code::ConflictingCast,
+                        path.clone(),
+                        format!(
+                            "cast '{}' of larvie '{}' is {:?} but '{}' declares it as {:?}",
+                            cast.primor, flat.primor, existing.flora, origin, cast.flora
+                        ),
+                    ).with_span(cast.span.as_ref()));
+                }
+                Some(existing) => *existing = cast.clone(),
+                None => flat.casts.push(cast.clone()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{fixture::cast, flora::Flora, instincts::Instinct, interface::Interface, mixin::Mixin, seal::Seal};
+
+    fn audited() -> Alveolus {
+        Alveolus::Mixin(Mixin {
+            primor: "Audited".to_string(),
+            casts: vec![cast("created_at", Flora::DateTime, vec![Seal::Vital]), cast("updated_at", Flora::DateTime, vec![])],
+            ..Default::default()
+        })
+    }
+
+    fn animal() -> Alveolus {
+        Alveolus::Larvie(Larvie {
+            primor: "Animal".to_string(),
+            casts: vec![cast("id", Flora::Uuid, vec![Seal::Core]), cast("name", Flora::Str, vec![])],
+            instincts: vec![Instinct { echo: "eat".to_string(), ..Default::default() }],
+            implements: vec!["Living".to_string()],
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_flatten_expands_parent_and_mixins() {
+        let ir = IR {
+            alveolus: vec![
+                audited(),
+                Alveolus::Larvie(Larvie {
+                    primor: "Bird".to_string(),
+                    casts: vec![cast("wings", Flora::U8, vec![]), cast("name", Flora::Str, vec![Seal::Vital])],
+                    instincts: vec![
+                        Instinct { echo: "eat".to_string(), ..Default::default() },
+                        Instinct { echo: "fly".to_string(), ..Default::default() },
+                    ],
+                    extends: Some("Animal".to_string()),
+                    mixins: vec!["Audited".to_string()],
+                    ..Default::default()
+                }),
+                animal(),
+                Alveolus::Interface(Interface { primor: "Living".to_string(), ..Default::default() }),
+            ],
+        };
+
+        let flat = ir.flatten().unwrap();
+
+        assert_eq!(flat.alveolus.len(), 3);
+        let Alveolus::Larvie(ref bird) = flat.alveolus[0] else { panic!("expected a larvie") };
+        let casts: Vec<&str> = bird.casts.iter().map(|cast| cast.primor.as_str()).collect();
+        assert_eq!(casts, vec!["id", "name", "created_at", "updated_at", "wings"]);
+        assert_eq!(bird.cast("name").unwrap().seals, vec![Seal::Vital]);
+        assert_eq!(bird.instincts.len(), 2);
+        assert_eq!(bird.implements, vec!["Living".to_string()]);
+        assert_eq!(bird.extends, None);
+        assert!(bird.mixins.is_empty());
+        assert_eq!(bird.key(), vec!["id"]);
+        assert!(flat.validate().is_empty());
+    }
+
+    #[test]
+    fn test_flatten_reports_conflicts() {
+        let ir = IR {
+            alveolus: vec![
+                audited(),
+                animal(),
+                Alveolus::Larvie(Larvie {
+                    primor: "Bird".to_string(),
+                    casts: vec![cast("name", Flora::Int, vec![]), cast("created_at", Flora::Date, vec![])],
+                    instincts: vec![Instinct { echo: "eat".to_string(), returns: Some(Flora::Bool), ..Default::default() }],
+                    extends: Some("Animal".to_string()),
+                    mixins: vec!["Audited".to_string(), "Tracked".to_string()],
+                    ..Default::default()
+                }),
+            ],
+        };
+
+        let diagnostics = ir.flatten().unwrap_err();
+        let codes: Vec<Code> = diagnostics.iter().map(|d| d.code).collect();
+
+        assert_eq!(codes, vec![Code::UnknownMixin, Code::ConflictingCast, Code::ConflictingCast, Code::SignatureMismatch]);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[2].mixins[1]");
+        assert_eq!(diagnostics[1].message, "cast 'name' of larvie 'Bird' is Str but 'Bird' declares it as Int");
+    }
+
+    #[test]
+    fn test_flatten_reports_cycles_and_unknown_parents() {
+        let extending = |primor: &str, parent: &str| {
+            Alveolus::Larvie(Larvie { primor: primor.to_string(), extends: Some(parent.to_string()), ..Default::default() })
+        };
+        let ir = IR { alveolus: vec![extending("Egg", "Bird"), extending("Bird", "Egg"), extending("Nest", "Tree")] };
+
+        let diagnostics = ir.flatten().unwrap_err();
+        let codes: Vec<Code> = diagnostics.iter().map(|d| d.code).collect();
+
+        assert_eq!(codes, vec![Code::InheritanceCycle, Code::UnknownParent]);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[0].extends");
+    }
+
+    #[test]
+    fn test_flatten_reports_duplicates_within_one_origin() {
+        let twice = |primor: &str| vec![cast(primor, Flora::Int, vec![]), cast(primor, Flora::Int, vec![])];
+        let ir = IR {
+            alveolus: vec![
+                Alveolus::Mixin(Mixin { primor: "Counted".to_string(), casts: twice("count"), ..Default::default() }),
+                Alveolus::Larvie(Larvie { primor: "Bird".to_string(), casts: twice("x"), mixins: vec!["Counted".to_string()], ..Default::default() }),
+            ],
+        };
+
+        let diagnostics = ir.flatten().unwrap_err();
+        let codes: Vec<Code> = diagnostics.iter().map(|d| d.code).collect();
+
+        assert_eq!(codes, vec![Code::DuplicateCast, Code::DuplicateCast]);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[1].mixins[0]");
+        assert_eq!(diagnostics[0].message, "cast 'count' is declared more than once in mixin 'Counted'");
+        assert_eq!(diagnostics[1].message, "cast 'x' is declared more than once in larvie 'Bird'");
+    }
+
+    #[test]
+    fn test_flatten_keeps_own_annotations() {
+        use crate::ir::{attribute::Attributes, literal::Literal};
+
+        let Alveolus::Larvie(mut animal) = animal() else { panic!("expected a larvie") };
+        animal.doc = Some("Anything alive.".to_string());
+        animal.attributes = Attributes::from([("ui.hidden".to_string(), Literal::Bool(true))]);
+        animal.casts[1].doc = Some("Common name.".to_string());
+        let ir = IR {
+            alveolus: vec![
+                Alveolus::Larvie(animal),
+                Alveolus::Larvie(Larvie { primor: "Bird".to_string(), extends: Some("Animal".to_string()), ..Default::default() }),
+            ],
+        };
+
+        let flat = ir.flatten().unwrap();
+
+        let Alveolus::Larvie(ref bird) = flat.alveolus[1] else { panic!("expected a larvie") };
+        assert_eq!(bird.doc, None);
+        assert!(bird.attributes.is_empty());
+        assert_eq!(bird.cast("name").unwrap().doc.as_deref(), Some("Common name."));
+    }
+
+    #[test]
+    fn test_flatten_keeps_own_generics() {
+        let ir = crate::ir::parse("bug Page<T> gene items List<T> end bug Feed gene page Page<Int> end").unwrap();
+        let extending = |item: Alveolus, parent: &str| match item {
+            Alveolus::Larvie(larvie) => Alveolus::Larvie(Larvie { extends: Some(parent.to_string()), ..larvie }),
+            other => other,
+        };
+        let ir = IR {
+            alveolus: vec![
+                crate::ir::fixture::larvie("Titled", vec![cast("title", Flora::Str, vec![])]),
+                extending(ir.alveolus[0].clone(), "Titled"),
+                extending(ir.alveolus[1].clone(), "Titled"),
+            ],
+        };
+
+        let flat = ir.flatten().unwrap();
+
+        let Alveolus::Larvie(ref page) = flat.alveolus[1] else { panic!("expected a larvie") };
+        let Alveolus::Larvie(ref feed) = flat.alveolus[2] else { panic!("expected a larvie") };
+        assert_eq!(page.generics, vec!["T".to_string()]);
+        assert!(feed.generics.is_empty());
+        assert!(flat.validate().is_empty());
+    }
+}
@@ -1,11 +1,141 @@
 use serde::{Deserialize, Serialize};
 
+use crate::dsl::tree::Specie;
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Flora {
     Int,
     Str,
     Bool,
+    Float,
+    Decimal { precision: u8, scale: u8 },
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    Date,
+    DateTime,
+    Duration,
+    Uuid,
+    Bytes,
     Bug(String),
+    List(Box<Flora>),
+    Optional(Box<Flora>),
+    Map(Box<Flora>, Box<Flora>),
+    Param(String),
+    Apply { bug: String, args: Vec<Flora> },
+}
+
+impl Flora {
+    /// Scalar floras hold a single value and may be used as `Map` keys.
+    pub fn is_scalar(&self) -> bool {
+        !matches!(
+            self,
+            Flora::Bug(_) | Flora::List(_) | Flora::Optional(_) | Flora::Map(_, _) | Flora::Param(_) | Flora::Apply { .. }
+        )
+    }
+
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            Flora::Int | Flora::I8 | Flora::I16 | Flora::I32 | Flora::I64 | Flora::U8 | Flora::U16 | Flora::U32 | Flora::U64
+        )
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.is_integer() || matches!(self, Flora::Float | Flora::Decimal { .. })
+    }
+
+    /// Names of every `Bug` referenced by this flora, including inside collections.
+    pub fn bugs(&self) -> Vec<&str> {
+        match self {
+            Flora::Bug(name) => vec![name.as_str()],
+            Flora::List(inner) | Flora::Optional(inner) => inner.bugs(),
+            Flora::Map(key, value) => {
+                let mut bugs = key.bugs();
+                bugs.extend(value.bugs());
+                bugs
+            }
+            Flora::Apply { bug, args } => {
+                let mut bugs = vec![bug.as_str()];
+                bugs.extend(args.iter().flat_map(Flora::bugs));
+                bugs
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Names of every type parameter used by this flora.
+    pub fn params(&self) -> Vec<&str> {
+        let mut params = Vec::new();
+        self.visit(&mut |flora| {
+            if let Flora::Param(name) = flora {
+                params.push(name.as_str());
+            }
+        });
+        params
+    }
+
+    /// Rebuilds this flora top-down, replacing every node for which `f` returns a flora.
+    pub fn rewrite(&self, f: &mut impl FnMut(&Flora) -> Option<Flora>) -> Flora {
+        if let Some(flora) = f(self) {
+            return flora;
+        }
+        match self {
+            Flora::List(inner) => Flora::List(Box::new(inner.rewrite(f))),
+            Flora::Optional(inner) => Flora::Optional(Box::new(inner.rewrite(f))),
+            Flora::Map(key, value) => Flora::Map(Box::new(key.rewrite(f)), Box::new(value.rewrite(f))),
+            Flora::Apply { bug, args } => Flora::Apply { bug: bug.clone(), args: args.iter().map(|arg| arg.rewrite(f)).collect() },
+            other => other.clone(),
+        }
+    }
+
+    fn visit<'a>(&'a self, f: &mut impl FnMut(&'a Flora)) {
+        f(self);
+        match self {
+            Flora::List(inner) | Flora::Optional(inner) => inner.visit(f),
+            Flora::Map(key, value) => {
+                key.visit(f);
+                value.visit(f);
+            }
+            Flora::Apply { args, .. } => args.iter().for_each(|arg| arg.visit(f)),
+            _ => {}
+        }
+    }
+}
+
+impl From<Specie> for Flora {
+    fn from(specie: Specie) -> Self {
+        match specie {
+            Specie::Int => Flora::Int,
+            Specie::Str => Flora::Str,
+            Specie::Bool => Flora::Bool,
+            Specie::Float => Flora::Float,
+            Specie::Decimal { precision, scale } => Flora::Decimal { precision, scale },
+            Specie::I8 => Flora::I8,
+            Specie::I16 => Flora::I16,
+            Specie::I32 => Flora::I32,
+            Specie::I64 => Flora::I64,
+            Specie::U8 => Flora::U8,
+            Specie::U16 => Flora::U16,
+            Specie::U32 => Flora::U32,
+            Specie::U64 => Flora::U64,
+            Specie::Date => Flora::Date,
+            Specie::DateTime => Flora::DateTime,
+            Specie::Duration => Flora::Duration,
+            Specie::Uuid => Flora::Uuid,
+            Specie::Bytes => Flora::Bytes,
+            Specie::Bug(name) => Flora::Bug(name),
+            Specie::List(inner) => Flora::List(Box::new((*inner).into())),
+            Specie::Optional(inner) => Flora::Optional(Box::new((*inner).into())),
+            Specie::Map(key, value) => Flora::Map(Box::new((*key).into()), Box::new((*value).into())),
+            Specie::Apply { bug, args } => Flora::Apply { bug, args: args.into_iter().map(Flora::from).collect() },
+        }
+    }
 }
 
 #[cfg(test)]
@@ -56,4 +186,108 @@ mod tests {
         assert_eq!(serde_json::to_string(&Flora::Bool).unwrap(), "\"Bool\"");
         assert_eq!(serde_json::to_string(&Flora::Bug("LadyBug".to_string())).unwrap(), "{\"Bug\":\"LadyBug\"}");
     }
+
+    #[test]
+    fn test_flora_from_specie() {
+        assert_eq!(Flora::from(Specie::Int), Flora::Int);
+        assert_eq!(Flora::from(Specie::Str), Flora::Str);
+        assert_eq!(Flora::from(Specie::Bool), Flora::Bool);
+        assert_eq!(Flora::from(Specie::Bug("Nest".to_string())), Flora::Bug("Nest".to_string()));
+    }
+
+    #[test]
+    fn test_flora_collections_json_format() {
+        let list = Flora::List(Box::new(Flora::Bug("Bee".to_string())));
+        let optional = Flora::Optional(Box::new(Flora::Str));
+        let map = Flora::Map(Box::new(Flora::Str), Box::new(Flora::List(Box::new(Flora::Int))));
+
+        assert_eq!(serde_json::to_string(&list).unwrap(), "{\"List\":{\"Bug\":\"Bee\"}}");
+        assert_eq!(serde_json::to_string(&optional).unwrap(), "{\"Optional\":\"Str\"}");
+        assert_eq!(serde_json::to_string(&map).unwrap(), "{\"Map\":[\"Str\",{\"List\":\"Int\"}]}");
+
+        for flora in [list, optional, map] {
+            let json = serde_json::to_string(&flora).unwrap();
+            assert_eq!(serde_json::from_str::<Flora>(&json).unwrap(), flora);
+        }
+    }
+
+    #[test]
+    fn test_flora_bugs() {
+        let flora = Flora::Map(
+            Box::new(Flora::Str),
+            Box::new(Flora::List(Box::new(Flora::Optional(Box::new(Flora::Bug("Bee".to_string())))))),
+        );
+
+        assert_eq!(flora.bugs(), vec!["Bee"]);
+        assert_eq!(Flora::Int.bugs(), Vec::<&str>::new());
+        assert!(Flora::Bool.is_scalar());
+        assert!(!Flora::List(Box::new(Flora::Int)).is_scalar());
+        assert!(!Flora::Bug("Bee".to_string()).is_scalar());
+    }
+
+    #[test]
+    fn test_flora_from_collection_specie() {
+        let specie = Specie::Map(Box::new(Specie::Str), Box::new(Specie::Optional(Box::new(Specie::Bug("Bee".to_string())))));
+
+        assert_eq!(
+            Flora::from(specie),
+            Flora::Map(Box::new(Flora::Str), Box::new(Flora::Optional(Box::new(Flora::Bug("Bee".to_string())))))
+        );
+    }
+
+    #[test]
+    fn test_flora_rich_scalars_json_format() {
+        let expected = [
+            (Flora::Float, "\"Float\""),
+            (Flora::Decimal { precision: 12, scale: 2 }, "{\"Decimal\":{\"precision\":12,\"scale\":2}}"),
+            (Flora::I8, "\"I8\""),
+            (Flora::I64, "\"I64\""),
+            (Flora::U16, "\"U16\""),
+            (Flora::U64, "\"U64\""),
+            (Flora::Date, "\"Date\""),
+            (Flora::DateTime, "\"DateTime\""),
+            (Flora::Duration, "\"Duration\""),
+            (Flora::Uuid, "\"Uuid\""),
+            (Flora::Bytes, "\"Bytes\""),
+        ];
+
+        for (flora, json) in expected {
+            assert_eq!(serde_json::to_string(&flora).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Flora>(json).unwrap(), flora);
+        }
+    }
+
+    #[test]
+    fn test_flora_numeric_classification() {
+        assert!(Flora::U8.is_integer());
+        assert!(Flora::Int.is_numeric());
+        assert!(Flora::Decimal { precision: 10, scale: 2 }.is_numeric());
+        assert!(!Flora::Float.is_integer());
+        assert!(!Flora::Duration.is_numeric());
+        assert!(Flora::Uuid.is_scalar());
+    }
+
+    #[test]
+    fn test_generic_floras() {
+        let page = Flora::Apply {
+            bug: "Page".to_string(),
+            args: vec![Flora::List(Box::new(Flora::Param("T".to_string()))), Flora::Bug("Bird".to_string())],
+        };
+
+        assert!(!page.is_scalar());
+        assert!(!Flora::Param("T".to_string()).is_scalar());
+        assert_eq!(page.bugs(), vec!["Page", "Bird"]);
+        assert_eq!(page.params(), vec!["T"]);
+
+        let bound = page.rewrite(&mut |flora| match flora {
+            Flora::Param(_) => Some(Flora::Int),
+            _ => None,
+        });
+        assert_eq!(bound.params(), Vec::<&str>::new());
+        assert_eq!(bound, Flora::Apply { bug: "Page".to_string(), args: vec![Flora::List(Box::new(Flora::Int)), Flora::Bug("Bird".to_string())] });
+
+        let json = serde_json::to_string(&page).unwrap();
+        assert_eq!(json, "{\"Apply\":{\"bug\":\"Page\",\"args\":[{\"List\":{\"Param\":\"T\"}},{\"Bug\":\"Bird\"}]}}");
+        assert_eq!(serde_json::from_str::<Flora>(&json).unwrap(), page);
+    }
 }
@@ -0,0 +1,361 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ir::{
+    IR,
+    alveolus::Alveolus,
+    casts::Casts,
+    diagnostic::Path,
+    flora::Flora,
+    seal::Seal,
+    symbol::{Handle, SymbolTable},
+};
+
+/// How a definition uses another one.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum DependencyKind {
+    /// A value type (union, alias, event) stored inline, so a cycle of these would have infinite size.
+    Embedding,
+    /// A larvie, which is a relationship to a row of its own, or a value behind `Optional`, `List`,
+    /// `Map`, a type argument or a cast with a `Seal::Reference`; cycles of these are legal.
+    Reference,
+}
+
+impl DependencyKind {
+    /// How a flora sealed with `seals` holds the bugs it names, before looking at what they are.
+    fn of(seals: &[Seal]) -> DependencyKind {
+        if seals.iter().any(|seal| matches!(seal, Seal::Reference { .. })) {
+            DependencyKind::Reference
+        } else {
+            DependencyKind::Embedding
+        }
+    }
+}
+
+/// An edge of the graph: the flora or seal at `path` of `from` names `to`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Dependency {
+    pub from: Handle,
+    pub to: Handle,
+    pub kind: DependencyKind,
+    pub path: Path,
+}
+
+/// Which alveolus each alveolus needs, built from the floras and reference seals of its casts
+/// (and of an alias' flora). Every alveolus is a node; names that do not resolve are left out.
+///
+/// Inherited and mixed-in casts only count once `IR::flatten` has copied them into the larvie,
+/// and a generic's type arguments are taken as references until `IR::monomorphize` expands them.
+#[derive(Debug, Clone)]
+pub struct DependencyGraph {
+    nodes: usize,
+    edges: Vec<Dependency>,
+}
+
+impl DependencyGraph {
+    pub fn new(ir: &IR) -> DependencyGraph {
+        let symbols = ir.symbols();
+        let mut edges = Vec::new();
+        for (i, alveolus) in ir.alveolus.iter().enumerate() {
+            let mut builder = Builder { symbols: &symbols, from: Handle(i), edges: &mut edges };
+            let path = Path::root().at("alveolus", i);
+            match alveolus {
+                Alveolus::Larvie(larvie) => builder.casts(&larvie.casts, &path.key("casts")),
+                Alveolus::Union(union) => {
+                    for (j, variant) in union.variants.iter().enumerate() {
+                        builder.casts(&variant.casts, &path.at("variants", j).key("casts"));
+                    }
+                }
+                Alveolus::Mixin(mixin) => builder.casts(&mixin.casts, &path.key("casts")),
+                Alveolus::Alias(alias) => {
+                    builder.flora(&alias.flora, DependencyKind::of(&alias.seals), &path.key("flora"));
+                    builder.seals(&alias.seals, &path);
+                }
+                Alveolus::Event(event) => builder.casts(&event.casts, &path.key("casts")),
+                Alveolus::View(view) => builder.casts(&view.computed, &path.key("computed")),
+                Alveolus::Enumeration(_) | Alveolus::Interface(_) | Alveolus::Module(_) | Alveolus::Lifecycle(_) => {}
+            }
+        }
+        DependencyGraph { nodes: ir.alveolus.len(), edges }
+    }
+
+    pub fn edges(&self) -> &[Dependency] {
+        &self.edges
+    }
+
+    /// The edges leaving `handle`, in declaration order.
+    pub fn dependencies(&self, handle: Handle) -> impl Iterator<Item = &Dependency> {
+        self.edges.iter().filter(move |edge| edge.from == handle)
+    }
+
+    /// Strongly connected components, each sorted by handle, with every component listed after
+    /// the components it depends on.
+    pub fn components(&self) -> Vec<Vec<Handle>> {
+        self.strongly_connected(|_| true)
+    }
+
+    /// Every alveolus, dependencies first; the members of a cycle keep their declaration order.
+    pub fn order(&self) -> Vec<Handle> {
+        self.components().into_iter().flatten().collect()
+    }
+
+    /// Components that loop back on themselves through any kind of edge, legal or not.
+    pub fn cycles(&self) -> Vec<Vec<Handle>> {
+        self.cyclic(self.components(), |_| true)
+    }
+
+    /// Components that loop back on themselves through embedding edges alone. Such values
+    /// would have infinite size, unlike cycles that pass through a reference.
+    pub fn embedding_cycles(&self) -> Vec<Vec<Handle>> {
+        let embedding = |kind: DependencyKind| kind == DependencyKind::Embedding;
+        self.cyclic(self.strongly_connected(embedding), embedding)
+    }
+
+    fn cyclic(&self, components: Vec<Vec<Handle>>, follow: impl Fn(DependencyKind) -> bool) -> Vec<Vec<Handle>> {
+        components
+            .into_iter()
+            .filter(|component| {
+                component.len() > 1 || self.edges.iter().any(|edge| follow(edge.kind) && edge.from == component[0] && edge.to == component[0])
+            })
+            .collect()
+    }
+
+    fn strongly_connected(&self, follow: impl Fn(DependencyKind) -> bool) -> Vec<Vec<Handle>> {
+        let mut successors = vec![Vec::new(); self.nodes];
+        for edge in self.edges.iter().filter(|edge| follow(edge.kind)) {
+            successors[edge.from.0].push(edge.to.0);
+        }
+
+        let mut tarjan = Tarjan {
+            successors,
+            index: vec![None; self.nodes],
+            low: vec![0; self.nodes],
+            on_stack: vec![false; self.nodes],
+            stack: Vec::new(),
+            next: 0,
+            components: Vec::new(),
+        };
+        for node in 0..self.nodes {
+            if tarjan.index[node].is_none() {
+                tarjan.visit(node);
+            }
+        }
+        tarjan.components
+    }
+}
+
+impl IR {
+    pub fn dependencies(&self) -> DependencyGraph {
+        DependencyGraph::new(self)
+    }
+}
+
+struct Builder<'a> {
+    symbols: &'a SymbolTable<'a>,
+    from: Handle,
+    edges: &'a mut Vec<Dependency>,
+}
+
+impl Builder<'_> {
+    fn casts(&mut self, casts: &[Casts], path: &Path) {
+        for (i, cast) in casts.iter().enumerate() {
+            let path = path.index(i);
+            self.flora(&cast.flora, DependencyKind::of(&cast.seals), &path.key("flora"));
+            self.seals(&cast.seals, &path);
+        }
+    }
+
+    fn seals(&mut self, seals: &[Seal], path: &Path) {
+        for (i, seal) in seals.iter().enumerate() {
+            if let Seal::Reference { larvie, .. } = seal {
+                self.edge(larvie, DependencyKind::Reference, path.at("seals", i));
+            }
+        }
+    }
+
+    fn flora(&mut self, flora: &Flora, kind: DependencyKind, path: &Path) {
+        match flora {
+            Flora::Bug(name) => self.edge(name, kind, path.clone()),
+            Flora::List(inner) | Flora::Optional(inner) => self.flora(inner, DependencyKind::Reference, path),
+            Flora::Map(key, value) => {
+                self.flora(key, DependencyKind::Reference, path);
+                self.flora(value, DependencyKind::Reference, path);
+            }
+            Flora::Apply { bug, args } => {
+                self.edge(bug, kind, path.clone());
+                for arg in args {
+                    self.flora(arg, DependencyKind::Reference, path);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn edge(&mut self, name: &str, kind: DependencyKind, path: Path) {
+        if let Some(to) = self.symbols.unique(name) {
+            let kind = match self.symbols.get(to) {
+                Alveolus::Larvie(_) => DependencyKind::Reference,
+                _ => kind,
+            };
+            self.edges.push(Dependency { from: self.from, to, kind, path });
+        }
+    }
+}
+
+/// Tarjan's algorithm; a component is emitted once everything it reaches has been.
+struct Tarjan {
+    successors: Vec<Vec<usize>>,
+    index: Vec<Option<usize>>,
+    low: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    next: usize,
+    components: Vec<Vec<Handle>>,
+}
+
+impl Tarjan {
+    fn visit(&mut self, node: usize) {
+        self.index[node] = Some(self.next);
+        self.low[node] = self.next;
+        self.next += 1;
+        self.stack.push(node);
+        self.on_stack[node] = true;
+
+        for i in 0..self.successors[node].len() {
+            let successor = self.successors[node][i];
+            match self.index[successor] {
+                None => {
+                    self.visit(successor);
+                    self.low[node] = self.low[node].min(self.low[successor]);
+                }
+                Some(index) if self.on_stack[successor] => self.low[node] = self.low[node].min(index),
+                Some(_) => {}
+            }
+        }
+
+        if Some(self.low[node]) == self.index[node] {
+            let mut component = Vec::new();
+            while let Some(member) = self.stack.pop() {
+                self.on_stack[member] = false;
+                component.push(Handle(member));
+                if member == node {
+                    break;
+                }
+            }
+            component.sort();
+            self.components.push(component);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{
+        alias::Alias,
+        enumeration::Enumeration,
+        fixture::{bug, cast, larvie},
+        seal::ReferentialAction,
+        union::{Union, Variant},
+    };
+
+    fn reference(larvie: &str) -> Seal {
+        Seal::Reference {
+            larvie: larvie.to_string(),
+            cast: "id".to_string(),
+            on_delete: ReferentialAction::SetNull,
+            on_update: ReferentialAction::NoAction,
+        }
+    }
+
+    fn union(primor: &str, variants: Vec<(&str, Flora)>) -> Alveolus {
+        Alveolus::Union(Union {
+            primor: primor.to_string(),
+            variants: variants
+                .into_iter()
+                .map(|(primor, flora)| Variant { primor: primor.to_string(), casts: vec![cast("value", flora, vec![])], ..Default::default() })
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_dependency_order() {
+        let ir = IR {
+            alveolus: vec![
+                larvie("Bird", vec![cast("nest", bug("Nest"), vec![]), cast("color", bug("Color"), vec![])]),
+                larvie("Nest", vec![cast("tree", bug("Tree"), vec![])]),
+                Alveolus::Enumeration(Enumeration { primor: "Color".to_string(), ..Default::default() }),
+                larvie("Tree", vec![cast("height", Flora::Int, vec![])]),
+            ],
+        };
+
+        let graph = ir.dependencies();
+
+        assert_eq!(graph.order(), vec![Handle(3), Handle(1), Handle(2), Handle(0)]);
+        assert!(graph.cycles().is_empty());
+        let bird: Vec<(usize, String)> = graph.dependencies(Handle(0)).map(|edge| (edge.to.0, edge.path.to_string())).collect();
+        assert_eq!(bird, vec![(1, "alveolus[0].casts[0].flora".to_string()), (2, "alveolus[0].casts[1].flora".to_string())]);
+    }
+
+    #[test]
+    fn test_reference_cycles_are_legal() {
+        let ir = IR {
+            alveolus: vec![
+                larvie("Bird", vec![cast("nest", Flora::Optional(Box::new(bug("Nest"))), vec![]), cast("flock", Flora::List(Box::new(bug("Bird"))), vec![])]),
+                larvie("Nest", vec![cast("owner", Flora::Uuid, vec![reference("Bird")])]),
+            ],
+        };
+
+        let graph = ir.dependencies();
+
+        assert_eq!(graph.cycles(), vec![vec![Handle(0), Handle(1)]]);
+        assert!(graph.embedding_cycles().is_empty());
+        assert!(graph.edges().iter().all(|edge| edge.kind == DependencyKind::Reference));
+        assert_eq!(graph.order(), vec![Handle(0), Handle(1)]);
+    }
+
+    #[test]
+    fn test_larvie_casts_are_references() {
+        let ir = IR {
+            alveolus: vec![
+                larvie("Bird", vec![cast("nest", bug("Nest"), vec![reference("Nest")])]),
+                larvie("Nest", vec![cast("owner", bug("Bird"), vec![])]),
+                union("Shape", vec![("Point", Flora::Int), ("Boxed", bug("Wrapper"))]),
+                Alveolus::Alias(Alias::new("Wrapper", bug("Shape"))),
+                Alveolus::Alias(Alias { seals: vec![reference("Nest")], ..Alias::new("ShapeId", bug("Shape")) }),
+            ],
+        };
+
+        let graph = ir.dependencies();
+
+        let kinds: Vec<(usize, usize, DependencyKind)> = graph.edges().iter().map(|edge| (edge.from.0, edge.to.0, edge.kind)).collect();
+        assert_eq!(kinds, vec![
+            (0, 1, DependencyKind::Reference),
+            (0, 1, DependencyKind::Reference),
+            (1, 0, DependencyKind::Reference),
+            (2, 3, DependencyKind::Embedding),
+            (3, 2, DependencyKind::Embedding),
+            (4, 2, DependencyKind::Reference),
+            (4, 1, DependencyKind::Reference),
+        ]);
+        assert_eq!(graph.embedding_cycles(), vec![vec![Handle(2), Handle(3)]]);
+    }
+
+    #[test]
+    fn test_embedding_cycles() {
+        let ir = IR {
+            alveolus: vec![
+                union("Expr", vec![("Literal", Flora::Int), ("Negate", bug("Expr"))]),
+                union("Tree", vec![("Leaf", Flora::Int), ("Node", Flora::List(Box::new(bug("Tree"))))]),
+                union("Egg", vec![("Chick", bug("Chick"))]),
+                union("Chick", vec![("Egg", bug("Egg")), ("Nest", bug("Nest"))]),
+                larvie("Nest", vec![cast("egg", bug("Egg"), vec![])]),
+            ],
+        };
+
+        let graph = ir.dependencies();
+
+        assert_eq!(graph.embedding_cycles(), vec![vec![Handle(0)], vec![Handle(2), Handle(3)]]);
+        assert_eq!(graph.cycles(), vec![vec![Handle(0)], vec![Handle(1)], vec![Handle(2), Handle(3), Handle(4)]]);
+    }
+}
@@ -1,8 +1,79 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+use crate::dsl::tree::{self, Ethics};
+use crate::ir::{attribute::Attributes, body::Stmt, flora::Flora, span::Span};
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Instinct {
     pub echo: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<Param>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub returns: Option<Flora>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub faults: Vec<String>,
+    /// Events this instinct raises.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub emits: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub body: Vec<Stmt>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Param {
+    pub primor: String,
+    pub flora: Flora,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+impl Param {
+    pub fn new(primor: impl Into<String>, flora: Flora) -> Param {
+        Param { primor: primor.into(), flora, doc: None, attributes: Attributes::new(), span: None }
+    }
+}
+
+impl Instinct {
+    /// Whether `other` has the same name, parameters, return flora and faults, ignoring spans.
+    pub fn same_signature(&self, other: &Instinct) -> bool {
+        self.echo == other.echo
+            && self.returns == other.returns
+            && self.faults == other.faults
+            && self.params.len() == other.params.len()
+            && self.params.iter().zip(&other.params).all(|(a, b)| a.primor == b.primor && a.flora == b.flora)
+    }
+}
+
+impl From<tree::Param> for Param {
+    fn from(param: tree::Param) -> Self {
+        Param {
+            span: Some(param.span),
+            ..Param::new(param.name, param.specie.into())
+        }
+    }
+}
+
+impl From<Ethics> for Instinct {
+    fn from(ethics: Ethics) -> Self {
+        Instinct {
+            echo: ethics.name,
+            params: ethics.params.into_iter().map(Param::from).collect(),
+            returns: ethics.returns.map(Flora::from),
+            faults: ethics.faults,
+            span: Some(ethics.span),
+            ..Default::default()
+        }
+    }
 }
 
 #[cfg(test)]
@@ -14,6 +85,7 @@ mod tests {
     fn test_instinct_creation() {
         let instinct = Instinct {
             echo: "test_action".to_string(),
+            ..Default::default()
         };
 
         assert_eq!(instinct.echo, "test_action");
@@ -23,6 +95,7 @@ mod tests {
     fn test_instinct_serialization() {
         let instinct = Instinct {
             echo: "serializable_action".to_string(),
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&instinct).unwrap();
@@ -35,6 +108,7 @@ mod tests {
     fn test_instinct_clone_and_eq() {
         let original = Instinct {
             echo: "original_action".to_string(),
+            ..Default::default()
         };
 
         let cloned = original.clone();
@@ -42,6 +116,7 @@ mod tests {
 
         let different = Instinct {
             echo: "different_action".to_string(),
+            ..Default::default()
         };
         assert_ne!(original, different);
     }
@@ -50,6 +125,7 @@ mod tests {
     fn test_instinct_empty_echo() {
         let instinct = Instinct {
             echo: "".to_string(),
+            ..Default::default()
         };
 
         assert_eq!(instinct.echo, "");
@@ -63,6 +139,7 @@ mod tests {
     fn test_instinct_unicode_support() {
         let instinct = Instinct {
             echo: "создать_用户_🚀".to_string(),
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&instinct).unwrap();
@@ -73,9 +150,9 @@ mod tests {
     #[test]
     fn test_instinct_vector() {
         let instincts = vec![
-            Instinct { echo: "action1".to_string() },
-            Instinct { echo: "action2".to_string() },
-            Instinct { echo: "action3".to_string() },
+            Instinct { echo: "action1".to_string(), ..Default::default() },
+            Instinct { echo: "action2".to_string(), ..Default::default() },
+            Instinct { echo: "action3".to_string(), ..Default::default() },
         ];
 
         let json = serde_json::to_string(&instincts).unwrap();
@@ -86,4 +163,39 @@ mod tests {
         assert_eq!(deserialized[1].echo, "action2");
         assert_eq!(deserialized[2].echo, "action3");
     }
+
+    #[test]
+    fn test_instinct_signature_serialization() {
+        let instinct = Instinct {
+            echo: "fly".to_string(),
+            params: vec![Param::new("height", Flora::Int)],
+            returns: Some(Flora::Bool),
+            faults: vec!["Tired".to_string()],
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&instinct).unwrap();
+
+        assert_eq!(
+            json,
+            "{\"echo\":\"fly\",\"params\":[{\"primor\":\"height\",\"flora\":\"Int\"}],\"returns\":\"Bool\",\"faults\":[\"Tired\"]}"
+        );
+        assert_eq!(serde_json::from_str::<Instinct>(&json).unwrap(), instinct);
+        assert_eq!(serde_json::to_string(&Instinct { echo: "rest".to_string(), ..Default::default() }).unwrap(), "{\"echo\":\"rest\"}");
+    }
+
+    #[test]
+    fn test_instinct_same_signature() {
+        let fly = Instinct {
+            echo: "fly".to_string(),
+            params: vec![Param::new("height", Flora::Int)],
+            returns: Some(Flora::Bool),
+            ..Default::default()
+        };
+        let spanned = Instinct { span: Some(Span::default()), ..fly.clone() };
+        let different = Instinct { returns: None, ..fly.clone() };
+
+        assert!(fly.same_signature(&spanned));
+        assert!(!fly.same_signature(&different));
+    }
 }
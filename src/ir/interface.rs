@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ir::{attribute::Attributes, instincts::Instinct, span::Span};
+
+/// Instinct signatures a `Larvie` promises to provide by listing the interface in `implements`.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Interface {
+    pub primor: String,
+    pub instincts: Vec<Instinct>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+impl Interface {
+    pub fn instinct(&self, echo: &str) -> Option<&Instinct> {
+        self.instincts.iter().find(|instinct| instinct.echo == echo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{flora::Flora, instincts::Param};
+    use serde_json;
+
+    fn archivable() -> Interface {
+        Interface {
+            primor: "Archivable".to_string(),
+            instincts: vec![
+                Instinct { echo: "archive".to_string(), returns: Some(Flora::Bool), ..Default::default() },
+                Instinct {
+                    echo: "restore".to_string(),
+                    params: vec![Param::new("at", Flora::DateTime)],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_interface_instinct_lookup() {
+        let archivable = archivable();
+
+        assert_eq!(archivable.instinct("archive").unwrap().returns, Some(Flora::Bool));
+        assert!(archivable.instinct("delete").is_none());
+    }
+
+    #[test]
+    fn test_interface_json_format() {
+        let archivable = archivable();
+
+        let json = serde_json::to_string(&archivable).unwrap();
+
+        assert_eq!(
+            json,
+            "{\"primor\":\"Archivable\",\"instincts\":[{\"echo\":\"archive\",\"returns\":\"Bool\"},{\"echo\":\"restore\",\"params\":[{\"primor\":\"at\",\"flora\":\"DateTime\"}]}]}"
+        );
+        assert_eq!(serde_json::from_str::<Interface>(&json).unwrap(), archivable);
+    }
+}
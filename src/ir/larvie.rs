@@ -1,18 +1,124 @@
 use serde::{Deserialize, Serialize};
 
-use crate::ir::{casts::Casts, instincts::Instinct};
+use crate::dsl::tree::Bug;
+use crate::ir::{
+    attribute::Attributes,
+    casts::Casts,
+    constraint::{Constraint, Index},
+    flora::Flora,
+    instincts::Instinct,
+    relation::Relation,
+    seal::Seal,
+    span::Span,
+};
 
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Larvie {
     pub primor: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generics: Vec<String>,
     pub casts: Vec<Casts>,
     pub instincts: Vec<Instinct>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relations: Vec<Relation>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub implements: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mixins: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub constraints: Vec<Constraint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub indexes: Vec<Index>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+impl Larvie {
+    pub fn cast(&self, primor: &str) -> Option<&Casts> {
+        self.casts.iter().find(|cast| cast.primor == primor)
+    }
+
+    /// Casts a storage backend persists, in declaration order.
+    pub fn stored_casts(&self) -> impl Iterator<Item = &Casts> {
+        self.casts.iter().filter(|cast| cast.is_stored())
+    }
+
+    /// Casts derived from their siblings, which code generators emit as getters.
+    pub fn computed_casts(&self) -> impl Iterator<Item = &Casts> {
+        self.casts.iter().filter(|cast| !cast.is_stored())
+    }
+
+    /// Casts forming the primary key: the `Key` constraint if declared, otherwise every `Core` cast.
+    pub fn key(&self) -> Vec<&str> {
+        self.constraints
+            .iter()
+            .find_map(|constraint| match constraint {
+                Constraint::Key { casts, .. } => Some(casts.iter().map(String::as_str).collect()),
+                Constraint::Unique { .. } => None,
+            })
+            .unwrap_or_else(|| {
+                self.casts
+                    .iter()
+                    .filter(|cast| cast.seals.contains(&Seal::Core))
+                    .map(|cast| cast.primor.as_str())
+                    .collect()
+            })
+    }
+
+    /// Rewrites every flora of the larvie's casts, parameters and returns with `Flora::rewrite`.
+    pub fn map_floras(&mut self, f: &mut impl FnMut(&Flora) -> Option<Flora>) {
+        for cast in &mut self.casts {
+            cast.flora = cast.flora.rewrite(f);
+        }
+        for instinct in &mut self.instincts {
+            for param in &mut instinct.params {
+                param.flora = param.flora.rewrite(f);
+            }
+            instinct.returns = instinct.returns.as_ref().map(|returns| returns.rewrite(f));
+        }
+    }
+
+    /// Whether `primor` alone identifies a row, through a seal or a single-cast constraint.
+    pub fn is_unique_cast(&self, primor: &str) -> bool {
+        let sealed = self
+            .cast(primor)
+            .is_some_and(|cast| cast.seals.iter().any(|seal| matches!(seal, Seal::Core | Seal::Root)));
+        sealed || self.constraints.iter().any(|constraint| constraint.casts() == [primor])
+    }
+}
+
+impl From<Bug> for Larvie {
+    fn from(bug: Bug) -> Self {
+        let mut larvie = Larvie {
+            primor: bug.name,
+            casts: bug.genes.into_iter().map(Casts::from).collect(),
+            instincts: bug.ethics.into_iter().map(Instinct::from).collect(),
+            span: Some(bug.span),
+            ..Default::default()
+        };
+        if !bug.generics.is_empty() {
+            // The parser cannot tell `T` from a bug named `T`; the declared generics settle it.
+            let generics = bug.generics;
+            larvie.map_floras(&mut |flora| match flora {
+                Flora::Bug(name) if generics.contains(name) => Some(Flora::Param(name.clone())),
+                _ => None,
+            });
+            larvie.generics = generics;
+        }
+        larvie
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ir::{flora::Flora, seal::Seal};
+    use crate::ir::seal::Seal;
     use serde_json;
 
     #[test]
@@ -21,6 +127,7 @@ mod tests {
             primor: "TestLarvie".to_string(),
             casts: vec![],
             instincts: vec![],
+            ..Default::default()
         };
 
         assert_eq!(larvie.primor, "TestLarvie");
@@ -34,20 +141,19 @@ mod tests {
             primor: "ComplexLarvie".to_string(),
             casts: vec![
                 Casts {
-                    primor: "field1".to_string(),
-                    flora: Flora::Int,
                     seals: vec![Seal::Vital],
+                    ..Casts::new("field1", Flora::Int)
                 },
                 Casts {
-                    primor: "field2".to_string(),
-                    flora: Flora::Str,
                     seals: vec![Seal::Core],
+                    ..Casts::new("field2", Flora::Str)
                 },
             ],
             instincts: vec![
-                Instinct { echo: "action1".to_string() },
-                Instinct { echo: "action2".to_string() },
+                Instinct { echo: "action1".to_string(), ..Default::default() },
+                Instinct { echo: "action2".to_string(), ..Default::default() },
             ],
+            ..Default::default()
         };
 
         assert_eq!(larvie.casts.len(), 2);
@@ -62,14 +168,14 @@ mod tests {
             primor: "SerializableLarvie".to_string(),
             casts: vec![
                 Casts {
-                    primor: "test_field".to_string(),
-                    flora: Flora::Bool,
                     seals: vec![Seal::Root],
+                    ..Casts::new("test_field", Flora::Bool)
                 }
             ],
             instincts: vec![
-                Instinct { echo: "test_action".to_string() }
+                Instinct { echo: "test_action".to_string(), ..Default::default() }
             ],
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&larvie).unwrap();
@@ -86,34 +192,35 @@ mod tests {
             primor: "SameLarvie".to_string(),
             casts: vec![
                 Casts {
-                    primor: "field".to_string(),
-                    flora: Flora::Int,
                     seals: vec![Seal::Vital],
+                    ..Casts::new("field", Flora::Int)
                 }
             ],
             instincts: vec![
-                Instinct { echo: "action".to_string() }
+                Instinct { echo: "action".to_string(), ..Default::default() }
             ],
+            ..Default::default()
         };
 
         let larvie2 = Larvie {
             primor: "SameLarvie".to_string(),
             casts: vec![
                 Casts {
-                    primor: "field".to_string(),
-                    flora: Flora::Int,
                     seals: vec![Seal::Vital],
+                    ..Casts::new("field", Flora::Int)
                 }
             ],
             instincts: vec![
-                Instinct { echo: "action".to_string() }
+                Instinct { echo: "action".to_string(), ..Default::default() }
             ],
+            ..Default::default()
         };
 
         let larvie3 = Larvie {
             primor: "DifferentLarvie".to_string(),
             casts: vec![],
             instincts: vec![],
+            ..Default::default()
         };
 
         assert_eq!(larvie1, larvie2);
@@ -126,14 +233,14 @@ mod tests {
             primor: "OriginalLarvie".to_string(),
             casts: vec![
                 Casts {
-                    primor: "field".to_string(),
-                    flora: Flora::Str,
                     seals: vec![Seal::Core, Seal::Root],
+                    ..Casts::new("field", Flora::Str)
                 }
             ],
             instincts: vec![
-                Instinct { echo: "complex_action".to_string() }
+                Instinct { echo: "complex_action".to_string(), ..Default::default() }
             ],
+            ..Default::default()
         };
 
         let cloned = original.clone();
@@ -150,34 +257,31 @@ mod tests {
             primor: "RichLarvie".to_string(),
             casts: vec![
                 Casts {
-                    primor: "id".to_string(),
-                    flora: Flora::Int,
                     seals: vec![Seal::Core, Seal::Vital],
+                    ..Casts::new("id", Flora::Int)
                 },
                 Casts {
-                    primor: "name".to_string(),
-                    flora: Flora::Str,
                     seals: vec![Seal::Vital],
+                    ..Casts::new("name", Flora::Str)
                 },
                 Casts {
-                    primor: "active".to_string(),
-                    flora: Flora::Bool,
                     seals: vec![Seal::Root],
+                    ..Casts::new("active", Flora::Bool)
                 },
                 Casts {
-                    primor: "metadata".to_string(),
-                    flora: Flora::Bug("Bee".to_string()),
                     seals: vec![],
+                    ..Casts::new("metadata", Flora::Bug("Bee".to_string()))
                 },
             ],
             instincts: vec![
-                Instinct { echo: "create".to_string() },
-                Instinct { echo: "read".to_string() },
-                Instinct { echo: "update".to_string() },
-                Instinct { echo: "delete".to_string() },
-                Instinct { echo: "activate".to_string() },
-                Instinct { echo: "deactivate".to_string() },
+                Instinct { echo: "create".to_string(), ..Default::default() },
+                Instinct { echo: "read".to_string(), ..Default::default() },
+                Instinct { echo: "update".to_string(), ..Default::default() },
+                Instinct { echo: "delete".to_string(), ..Default::default() },
+                Instinct { echo: "activate".to_string(), ..Default::default() },
+                Instinct { echo: "deactivate".to_string(), ..Default::default() },
             ],
+            ..Default::default()
         };
 
         assert_eq!(larvie.casts.len(), 4);
@@ -188,4 +292,113 @@ mod tests {
         let deserialized: Larvie = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized, larvie);
     }
+
+    #[test]
+    fn test_larvie_from_bug() {
+        use crate::dsl::tree::{Bug, Ethics, Gene, Specie};
+
+        let span = |start, end| Span { file: None, start, end, line: 1, col: start + 1 };
+        let bug = Bug {
+            name: "Bird".to_string(),
+            generics: vec![],
+            genes: vec![Gene { name: "energy".to_string(), specie: Specie::Int, span: span(9, 24) }],
+            ethics: vec![Ethics {
+                name: "fly".to_string(),
+                params: vec![],
+                returns: Some(Specie::Bool),
+                faults: vec![],
+                span: span(25, 35),
+            }],
+            span: span(0, 39),
+        };
+
+        assert_eq!(Larvie::from(bug), Larvie {
+            primor: "Bird".to_string(),
+            casts: vec![
+                Casts {
+                    seals: vec![Seal::Vital],
+                    span: Some(span(9, 24)),
+                    ..Casts::new("energy", Flora::Int)
+                }
+            ],
+            instincts: vec![
+                Instinct {
+                    echo: "fly".to_string(),
+                    returns: Some(Flora::Bool),
+                    span: Some(span(25, 35)),
+                    ..Default::default()
+                }
+            ],
+            span: Some(span(0, 39)),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn test_larvie_key() {
+        let cast = |primor: &str, seals| Casts { seals, ..Casts::new(primor, Flora::Int) };
+        let mut larvie = Larvie {
+            primor: "Cell".to_string(),
+            casts: vec![cast("id", vec![Seal::Core]), cast("hive", vec![]), cast("slot", vec![])],
+            ..Default::default()
+        };
+
+        assert_eq!(larvie.key(), vec!["id"]);
+        assert!(larvie.is_unique_cast("id"));
+        assert!(!larvie.is_unique_cast("slot"));
+
+        larvie.constraints = vec![
+            Constraint::key(vec!["hive".to_string(), "slot".to_string()]),
+            Constraint::unique(vec!["slot".to_string()]),
+        ];
+
+        assert_eq!(larvie.key(), vec!["hive", "slot"]);
+        assert!(larvie.is_unique_cast("slot"));
+        assert!(!larvie.is_unique_cast("hive"));
+        assert!(larvie.cast("hive").is_some());
+        assert!(larvie.cast("wing").is_none());
+    }
+
+    #[test]
+    fn test_larvie_omits_empty_constraints_in_json() {
+        let larvie = Larvie { primor: "Bare".to_string(), ..Default::default() };
+
+        let json = serde_json::to_string(&larvie).unwrap();
+
+        assert_eq!(json, "{\"primor\":\"Bare\",\"casts\":[],\"instincts\":[]}");
+    }
+
+    #[test]
+    fn test_larvie_from_generic_bug() {
+        use crate::ir::parse;
+
+        let ir = parse("bug Page<T> gene items List<T> gene owner Bird ethics first: Optional<T> end").unwrap();
+        let crate::ir::Alveolus::Larvie(ref page) = ir.alveolus[0] else { panic!("expected a larvie") };
+
+        assert_eq!(page.generics, vec!["T".to_string()]);
+        assert_eq!(page.casts[0].flora, Flora::List(Box::new(Flora::Param("T".to_string()))));
+        assert_eq!(page.casts[1].flora, Flora::Bug("Bird".to_string()));
+        assert_eq!(page.instincts[0].returns, Some(Flora::Optional(Box::new(Flora::Param("T".to_string())))));
+    }
+
+    #[test]
+    fn test_stored_and_computed_casts() {
+        use crate::ir::body::Expr;
+
+        let larvie = Larvie {
+            primor: "Bird".to_string(),
+            casts: vec![
+                Casts::new("first", Flora::Str),
+                Casts { computed: Some(Expr::Cast("first".to_string())), ..Casts::new("label", Flora::Str) },
+                Casts::new("last", Flora::Str),
+            ],
+            ..Default::default()
+        };
+
+        let stored: Vec<&str> = larvie.stored_casts().map(|cast| cast.primor.as_str()).collect();
+        let computed: Vec<&str> = larvie.computed_casts().map(|cast| cast.primor.as_str()).collect();
+
+        assert_eq!(stored, vec!["first", "last"]);
+        assert_eq!(computed, vec!["label"]);
+    }
 }
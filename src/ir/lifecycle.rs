@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ir::{IR, alveolus::Alveolus, attribute::Attributes, span::Span};
+
+/// A state machine over the rows of `larvie`: each transition is taken by running one of its
+/// instincts, which is therefore only legal in the transition's `from` state.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Lifecycle {
+    pub primor: String,
+    pub larvie: String,
+    pub initial: String,
+    pub states: Vec<State>,
+    pub transitions: Vec<Transition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+/// A named state; a `terminal` state is where a row may legitimately stop.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct State {
+    pub primor: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub terminal: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Transition {
+    pub from: String,
+    pub to: String,
+    pub instinct: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+impl Lifecycle {
+    pub fn state(&self, primor: &str) -> Option<&State> {
+        self.states.iter().find(|state| state.primor == primor)
+    }
+
+    /// States in which `instinct` may run, in declaration order of their transitions.
+    pub fn allowed_in(&self, instinct: &str) -> Vec<&str> {
+        let mut states = Vec::new();
+        for transition in self.transitions.iter().filter(|transition| transition.instinct == instinct) {
+            if !states.contains(&transition.from.as_str()) {
+                states.push(transition.from.as_str());
+            }
+        }
+        states
+    }
+
+    /// The state reached by running `instinct` in `state`, if that is allowed.
+    pub fn next(&self, state: &str, instinct: &str) -> Option<&str> {
+        self.transitions
+            .iter()
+            .find(|transition| transition.from == state && transition.instinct == instinct)
+            .map(|transition| transition.to.as_str())
+    }
+
+    /// States reachable from `initial` through the transitions, `initial` included.
+    pub fn reachable(&self) -> HashSet<&str> {
+        let mut reached = HashSet::from([self.initial.as_str()]);
+        let mut pending = vec![self.initial.as_str()];
+        while let Some(state) = pending.pop() {
+            for transition in self.transitions.iter().filter(|transition| transition.from == state) {
+                if reached.insert(transition.to.as_str()) {
+                    pending.push(transition.to.as_str());
+                }
+            }
+        }
+        reached
+    }
+}
+
+impl IR {
+    /// Every lifecycle declared over the larvie named `larvie`.
+    pub fn lifecycles(&self, larvie: &str) -> Vec<&Lifecycle> {
+        self.alveolus
+            .iter()
+            .filter_map(|alveolus| match alveolus {
+                Alveolus::Lifecycle(lifecycle) if lifecycle.larvie == larvie => Some(lifecycle),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    fn state(primor: &str, terminal: bool) -> State {
+        State { primor: primor.to_string(), terminal, ..Default::default() }
+    }
+
+    fn transition(from: &str, to: &str, instinct: &str) -> Transition {
+        Transition { from: from.to_string(), to: to.to_string(), instinct: instinct.to_string(), ..Default::default() }
+    }
+
+    fn growth() -> Lifecycle {
+        Lifecycle {
+            primor: "Growth".to_string(),
+            larvie: "Bee".to_string(),
+            initial: "Egg".to_string(),
+            states: vec![state("Egg", false), state("Larva", false), state("Adult", true), state("Fossil", true)],
+            transitions: vec![transition("Egg", "Larva", "hatch"), transition("Larva", "Adult", "molt"), transition("Egg", "Egg", "warm")],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_lifecycle_queries() {
+        let growth = growth();
+
+        assert_eq!(growth.allowed_in("hatch"), vec!["Egg"]);
+        assert!(growth.allowed_in("fly").is_empty());
+        assert_eq!(growth.next("Larva", "molt"), Some("Adult"));
+        assert_eq!(growth.next("Adult", "molt"), None);
+        assert_eq!(growth.reachable(), HashSet::from(["Egg", "Larva", "Adult"]));
+        assert!(growth.state("Adult").unwrap().terminal);
+    }
+
+    #[test]
+    fn test_lifecycle_json_format() {
+        let lifecycle = Lifecycle {
+            primor: "Growth".to_string(),
+            larvie: "Bee".to_string(),
+            initial: "Egg".to_string(),
+            states: vec![state("Egg", false), state("Adult", true)],
+            transitions: vec![transition("Egg", "Adult", "hatch")],
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&lifecycle).unwrap();
+
+        assert_eq!(
+            json,
+            "{\"primor\":\"Growth\",\"larvie\":\"Bee\",\"initial\":\"Egg\",\
+             \"states\":[{\"primor\":\"Egg\"},{\"primor\":\"Adult\",\"terminal\":true}],\
+             \"transitions\":[{\"from\":\"Egg\",\"to\":\"Adult\",\"instinct\":\"hatch\"}]}"
+        );
+        assert_eq!(serde_json::from_str::<Lifecycle>(&json).unwrap(), lifecycle);
+    }
+
+    #[test]
+    fn test_lifecycles_of_larvie() {
+        let ir = IR { alveolus: vec![Alveolus::Lifecycle(growth()), Alveolus::Lifecycle(Lifecycle { larvie: "Bird".to_string(), ..growth() })] };
+
+        assert_eq!(ir.lifecycles("Bee").len(), 1);
+        assert!(ir.lifecycles("Nest").is_empty());
+    }
+}
@@ -0,0 +1,110 @@
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ir::flora::Flora;
+
+/// A constant value written in the model, e.g. a seal's default or bounds.
+///
+/// Floats keep their source text so that literals, and every node holding one, stay `Eq`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Literal {
+    Int(i64),
+    Float(String),
+    Str(String),
+    Bool(bool),
+    Null,
+}
+
+impl Literal {
+    /// Whether this literal is a valid value for a cast of the given flora.
+    pub fn fits(&self, flora: &Flora) -> bool {
+        match (self, flora) {
+            (Literal::Null, Flora::Optional(_)) => true,
+            (literal, Flora::Optional(inner)) => literal.fits(inner),
+            (Literal::Int(value), flora) if flora.is_integer() => int_fits(*value, flora),
+            (Literal::Int(_), Flora::Float | Flora::Decimal { .. }) => true,
+            (Literal::Float(text), Flora::Float | Flora::Decimal { .. }) => text.parse::<f64>().is_ok(),
+            (Literal::Str(_), Flora::Str | Flora::Date | Flora::DateTime | Flora::Duration | Flora::Uuid) => true,
+            (Literal::Bool(_), Flora::Bool) => true,
+            _ => false,
+        }
+    }
+
+    /// Orders two literals of the same kind; numbers compare by value, strings lexicographically.
+    pub fn compare(&self, other: &Literal) -> Option<Ordering> {
+        match (self, other) {
+            (Literal::Str(a), Literal::Str(b)) => Some(a.cmp(b)),
+            (Literal::Bool(a), Literal::Bool(b)) => Some(a.cmp(b)),
+            (a, b) => a.as_f64()?.partial_cmp(&b.as_f64()?),
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Literal::Int(value) => Some(*value as f64),
+            Literal::Float(text) => text.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+fn int_fits(value: i64, flora: &Flora) -> bool {
+    match flora {
+        Flora::I8 => i8::try_from(value).is_ok(),
+        Flora::I16 => i16::try_from(value).is_ok(),
+        Flora::I32 => i32::try_from(value).is_ok(),
+        Flora::U8 => u8::try_from(value).is_ok(),
+        Flora::U16 => u16::try_from(value).is_ok(),
+        Flora::U32 => u32::try_from(value).is_ok(),
+        Flora::U64 => value >= 0,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_literal_fits() {
+        assert!(Literal::Int(100).fits(&Flora::Int));
+        assert!(Literal::Int(100).fits(&Flora::Decimal { precision: 10, scale: 2 }));
+        assert!(Literal::Float("0.5".to_string()).fits(&Flora::Float));
+        assert!(Literal::Str("2025-06-08".to_string()).fits(&Flora::Date));
+        assert!(Literal::Null.fits(&Flora::Optional(Box::new(Flora::Str))));
+        assert!(Literal::Bool(true).fits(&Flora::Optional(Box::new(Flora::Bool))));
+
+        assert!(!Literal::Null.fits(&Flora::Str));
+        assert!(!Literal::Str("100".to_string()).fits(&Flora::Int));
+        assert!(!Literal::Float("1.5".to_string()).fits(&Flora::Int));
+        assert!(!Literal::Float("abc".to_string()).fits(&Flora::Float));
+        assert!(!Literal::Int(1).fits(&Flora::List(Box::new(Flora::Int))));
+    }
+
+    #[test]
+    fn test_literal_fits_integer_width() {
+        assert!(Literal::Int(255).fits(&Flora::U8));
+        assert!(!Literal::Int(256).fits(&Flora::U8));
+        assert!(!Literal::Int(-1).fits(&Flora::U64));
+        assert!(Literal::Int(-128).fits(&Flora::I8));
+        assert!(!Literal::Int(-129).fits(&Flora::I8));
+    }
+
+    #[test]
+    fn test_literal_compare() {
+        assert_eq!(Literal::Int(1).compare(&Literal::Int(2)), Some(Ordering::Less));
+        assert_eq!(Literal::Int(2).compare(&Literal::Float("1.5".to_string())), Some(Ordering::Greater));
+        assert_eq!(Literal::Str("b".to_string()).compare(&Literal::Str("a".to_string())), Some(Ordering::Greater));
+        assert_eq!(Literal::Int(1).compare(&Literal::Str("1".to_string())), None);
+        assert_eq!(Literal::Null.compare(&Literal::Null), None);
+    }
+
+    #[test]
+    fn test_literal_json_format() {
+        assert_eq!(serde_json::to_string(&Literal::Int(100)).unwrap(), "{\"Int\":100}");
+        assert_eq!(serde_json::to_string(&Literal::Float("0.5".to_string())).unwrap(), "{\"Float\":\"0.5\"}");
+        assert_eq!(serde_json::to_string(&Literal::Null).unwrap(), "\"Null\"");
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ir::{attribute::Attributes, casts::Casts, span::Span};
+
+/// A reusable group of casts, such as audit fields, that larvies include through `mixins`.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Mixin {
+    pub primor: String,
+    pub casts: Vec<Casts>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{flora::Flora, seal::Seal};
+    use serde_json;
+
+    #[test]
+    fn test_mixin_json_format() {
+        let audited = Mixin {
+            primor: "Audited".to_string(),
+            casts: vec![Casts { seals: vec![Seal::Vital], ..Casts::new("created_at", Flora::DateTime) }],
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&audited).unwrap();
+
+        assert_eq!(json, "{\"primor\":\"Audited\",\"casts\":[{\"primor\":\"created_at\",\"flora\":\"DateTime\",\"seals\":[\"Vital\"]}]}");
+        assert_eq!(serde_json::from_str::<Mixin>(&json).unwrap(), audited);
+    }
+}
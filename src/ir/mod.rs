@@ -4,22 +4,87 @@ pub mod flora;
 pub mod seal;
 pub mod casts;
 pub mod instincts;
+pub mod diagnostic;
+pub mod span;
+pub mod literal;
+pub mod constraint;
+pub mod body;
+pub mod enumeration;
+pub mod union;
+pub mod interface;
+pub mod mixin;
+pub mod alias;
+pub mod relation;
+pub mod attribute;
+pub mod module;
+pub mod lifecycle;
+pub mod event;
+pub mod view;
+pub mod symbol;
+pub mod graph;
+pub mod validate;
+pub mod flatten;
+pub mod monomorphize;
+#[cfg(test)]
+mod fixture;
 
 use serde::{Deserialize, Serialize};
 
+use crate::dsl::{ParseError, Tree};
+
 // Re-export public types
 pub use larvie::Larvie;
 pub use flora::Flora;
-pub use seal::Seal;
+pub use seal::{ReferentialAction, Seal};
 pub use casts::Casts;
-pub use instincts::Instinct;
+pub use instincts::{Instinct, Param};
 pub use alveolus::Alveolus;
+pub use diagnostic::{Code, Diagnostic, Path, Severity};
+pub use span::Span;
+pub use literal::Literal;
+pub use constraint::{Constraint, Index, IndexPart, Order};
+pub use body::{BinOp, Expr, Stmt, UnOp};
+pub use enumeration::{Enumeration, Member};
+pub use union::{Union, Variant};
+pub use interface::Interface;
+pub use mixin::Mixin;
+pub use alias::{Alias, AliasKind};
+pub use relation::{Cardinality, Relation};
+pub use attribute::{Annotated, Attributes};
+pub use module::{Import, Module};
+pub use lifecycle::{Lifecycle, State, Transition};
+pub use event::Event;
+pub use view::{Selection, View};
+pub use symbol::{Handle, Reference, Bindings, SymbolTable};
+pub use graph::{Dependency, DependencyGraph, DependencyKind};
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct IR {
     pub alveolus: Vec<Alveolus>,
 }
 
+impl From<Tree> for IR {
+    fn from(tree: Tree) -> Self {
+        IR {
+            alveolus: tree.bugs.into_iter().map(|bug| Alveolus::Larvie(bug.into())).collect(),
+        }
+    }
+}
+
+pub fn sc_tree_to_ir(tree: Tree) -> IR {
+    IR::from(tree)
+}
+
+/// Parses SC-DSL source straight into an `IR`.
+pub fn parse(source: &str) -> Result<IR, ParseError> {
+    Tree::parse_input(source.to_string()).map(sc_tree_to_ir)
+}
+
+/// Like `parse`, with `file` recorded in the span of every node.
+pub fn parse_file(file: &str, source: &str) -> Result<IR, ParseError> {
+    Tree::parse_file(file, source.to_string()).map(sc_tree_to_ir)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,14 +105,14 @@ mod tests {
             primor: "TestLarvie".to_string(),
             casts: vec![
                 Casts {
-                    primor: "field".to_string(),
-                    flora: Flora::Int,
                     seals: vec![Seal::Vital],
+                    ..Casts::new("field", Flora::Int)
                 }
             ],
             instincts: vec![
-                Instinct { echo: "action".to_string() }
+                Instinct { echo: "action".to_string(), ..Default::default() }
             ],
+            ..Default::default()
         };
 
         let ir = IR {
@@ -65,6 +130,7 @@ mod tests {
                 assert_eq!(l.casts.len(), 1);
                 assert_eq!(l.instincts.len(), 1);
             }
+            _ => panic!("expected a larvie"),
         }
     }
 
@@ -74,14 +140,14 @@ mod tests {
             primor: "SerializableLarvie".to_string(),
             casts: vec![
                 Casts {
-                    primor: "test_field".to_string(),
-                    flora: Flora::Bool,
                     seals: vec![Seal::Root],
+                    ..Casts::new("test_field", Flora::Bool)
                 }
             ],
             instincts: vec![
-                Instinct { echo: "test_action".to_string() }
+                Instinct { echo: "test_action".to_string(), ..Default::default() }
             ],
+            ..Default::default()
         };
 
         let ir = IR {
@@ -101,18 +167,21 @@ mod tests {
             primor: "SameLarvie".to_string(),
             casts: vec![],
             instincts: vec![],
+            ..Default::default()
         };
 
         let larvie2 = Larvie {
             primor: "SameLarvie".to_string(),
             casts: vec![],
             instincts: vec![],
+            ..Default::default()
         };
 
         let larvie3 = Larvie {
             primor: "DifferentLarvie".to_string(),
             casts: vec![],
             instincts: vec![],
+            ..Default::default()
         };
 
         let ir1 = IR {
@@ -137,14 +206,14 @@ mod tests {
             primor: "CloneLarvie".to_string(),
             casts: vec![
                 Casts {
-                    primor: "field".to_string(),
-                    flora: Flora::Str,
                     seals: vec![Seal::Core],
+                    ..Casts::new("field", Flora::Str)
                 }
             ],
             instincts: vec![
-                Instinct { echo: "action".to_string() }
+                Instinct { echo: "action".to_string(), ..Default::default() }
             ],
+            ..Default::default()
         };
 
         let original = IR {
@@ -164,35 +233,34 @@ mod tests {
             primor: "Larvie1".to_string(),
             casts: vec![
                 Casts {
-                    primor: "id".to_string(),
-                    flora: Flora::Int,
                     seals: vec![Seal::Core],
+                    ..Casts::new("id", Flora::Int)
                 }
             ],
             instincts: vec![
-                Instinct { echo: "create".to_string() },
-                Instinct { echo: "read".to_string() },
+                Instinct { echo: "create".to_string(), ..Default::default() },
+                Instinct { echo: "read".to_string(), ..Default::default() },
             ],
+            ..Default::default()
         };
 
         let larvie2 = Larvie {
             primor: "Larvie2".to_string(),
             casts: vec![
                 Casts {
-                    primor: "name".to_string(),
-                    flora: Flora::Str,
                     seals: vec![Seal::Vital],
+                    ..Casts::new("name", Flora::Str)
                 },
                 Casts {
-                    primor: "active".to_string(),
-                    flora: Flora::Bool,
                     seals: vec![Seal::Root],
+                    ..Casts::new("active", Flora::Bool)
                 },
             ],
             instincts: vec![
-                Instinct { echo: "update".to_string() },
-                Instinct { echo: "delete".to_string() },
+                Instinct { echo: "update".to_string(), ..Default::default() },
+                Instinct { echo: "delete".to_string(), ..Default::default() },
             ],
+            ..Default::default()
         };
 
         let ir = IR {
@@ -216,6 +284,7 @@ mod tests {
             primor: "DebugLarvie".to_string(),
             casts: vec![],
             instincts: vec![],
+            ..Default::default()
         };
 
         let ir = IR {
@@ -227,4 +296,46 @@ mod tests {
         assert!(debug_str.contains("alveolus"));
         assert!(debug_str.contains("DebugLarvie"));
     }
+
+    #[test]
+    fn test_sc_tree_to_ir() {
+        let tree = Tree::parse_input("
+            bug Bird
+                gene energy Int
+                ethics fly
+            end
+            bug Nest
+                gene owner Bird
+            end
+        ".to_string()).unwrap();
+
+        let ir = sc_tree_to_ir(tree);
+
+        assert_eq!(ir.alveolus.len(), 2);
+        match &ir.alveolus[1] {
+            Alveolus::Larvie(l) => {
+                assert_eq!(l.primor, "Nest");
+                assert_eq!(l.casts[0].flora, Flora::Bug("Bird".to_string()));
+                assert_eq!(l.casts[0].seals, vec![Seal::Vital]);
+            }
+            _ => panic!("expected a larvie"),
+        }
+    }
+
+    #[test]
+    fn test_parse_reports_errors() {
+        let error = parse("bug Bird\n  gene energy\n").unwrap_err();
+
+        assert_eq!((error.line, error.col), (3, 1));
+        assert_eq!(error.to_string(), "3:1: expected specie, found end of input");
+    }
+
+    #[test]
+    fn test_parse_file_reports_errors_with_file() {
+        let error = parse_file("bird.sc", "bug Bird\n  gene energy\n").unwrap_err();
+        assert_eq!(error.to_string(), "bird.sc:3:1: expected specie, found end of input");
+
+        let error = parse_file("bird.sc", "bug Bird ?").unwrap_err();
+        assert_eq!(error.to_string(), "bird.sc:1:10: unexpected character '?'");
+    }
 }
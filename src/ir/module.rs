@@ -0,0 +1,429 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ir::{
+    IR,
+    alveolus::Alveolus,
+    attribute::Attributes,
+    casts::Casts,
+    diagnostic::{Code, Diagnostic, Path},
+    flora::Flora,
+    instincts::Instinct,
+    seal::Seal,
+    span::Span,
+};
+
+/// Joins a module path and a name: `billing::Invoice`.
+pub const SEPARATOR: &str = "::";
+
+/// A namespace of alveolus; its items are referred to as `module::Name` from outside.
+///
+/// Names listed in `private` are only visible inside the module and the modules nested in it.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Module {
+    pub primor: String,
+    pub alveolus: Vec<Alveolus>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub imports: Vec<Import>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub private: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+/// Brings the item at the qualified `path` into scope under `alias`, or its own name.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Import {
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+impl Import {
+    /// The name the import is known by inside the importing module.
+    pub fn name(&self) -> &str {
+        self.alias.as_deref().unwrap_or_else(|| self.path.rsplit(SEPARATOR).next().unwrap_or(&self.path))
+    }
+}
+
+/// The qualified name of `primor` declared in the module at `scope`, where `""` is the root.
+pub fn qualify(scope: &str, primor: &str) -> String {
+    if scope.is_empty() { primor.to_string() } else { format!("{}{}{}", scope, SEPARATOR, primor) }
+}
+
+/// The module enclosing `scope`, or `None` at the root.
+fn parent(scope: &str) -> Option<&str> {
+    match scope {
+        "" => None,
+        scope => Some(scope.rsplit_once(SEPARATOR).map_or("", |(parent, _)| parent)),
+    }
+}
+
+impl IR {
+    /// The module at the qualified `path`; `None` for the root or an unknown path.
+    pub fn module(&self, path: &str) -> Option<&Module> {
+        let mut items = &self.alveolus;
+        let mut found = None;
+        for segment in path.split(SEPARATOR) {
+            let module = items.iter().find_map(|alveolus| match alveolus {
+                Alveolus::Module(module) if module.primor == segment => Some(module),
+                _ => None,
+            })?;
+            items = &module.alveolus;
+            found = Some(module);
+        }
+        found
+    }
+
+    /// The definition behind a qualified name such as `billing::Invoice`, from the root.
+    ///
+    /// This walks the module tree as written. Once `IR::link` has flattened it, build a
+    /// `SymbolTable` instead to look up many names at once.
+    pub fn lookup(&self, qualified: &str) -> Option<&Alveolus> {
+        let (scope, primor) = qualified.rsplit_once(SEPARATOR).unwrap_or(("", qualified));
+        let items = if scope.is_empty() { &self.alveolus } else { &self.module(scope)?.alveolus };
+        items.iter().find(|alveolus| alveolus.primor() == primor && !matches!(alveolus, Alveolus::Module(_)))
+    }
+
+    /// Maps `name`, as written inside the module at `scope`, to the qualified name of its definition.
+    ///
+    /// Qualified names are read from the root. A bare name is looked up among the items and
+    /// imports of `scope`, then of each enclosing module out to the root.
+    pub fn resolve(&self, scope: &str, name: &str) -> Option<String> {
+        if name.contains(SEPARATOR) {
+            return self.lookup(name).map(|_| name.to_string());
+        }
+        let mut current = Some(scope);
+        while let Some(scope) = current {
+            let qualified = qualify(scope, name);
+            if self.lookup(&qualified).is_some() {
+                return Some(qualified);
+            }
+            if let Some(import) = self.module(scope).and_then(|module| module.imports.iter().find(|import| import.name() == name))
+                && self.lookup(&import.path).is_some()
+            {
+                return Some(import.path.clone());
+            }
+            current = parent(scope);
+        }
+        None
+    }
+
+    /// Whether code in the module at `scope` may refer to the `qualified` definition.
+    pub fn is_visible(&self, qualified: &str, scope: &str) -> bool {
+        self.private_owner(qualified).is_none_or(|owner| scope == owner || scope.starts_with(&format!("{}{}", owner, SEPARATOR)))
+    }
+
+    /// The innermost module hiding `qualified`, or one of the modules on its path, from the outside.
+    fn private_owner<'a>(&self, qualified: &'a str) -> Option<&'a str> {
+        let mut owner = None;
+        for (end, _) in qualified.match_indices(SEPARATOR) {
+            let (scope, rest) = (&qualified[..end], &qualified[end + SEPARATOR.len()..]);
+            let name = rest.split(SEPARATOR).next().unwrap_or(rest);
+            if self.module(scope).is_some_and(|module| module.private.iter().any(|private| private == name)) {
+                owner = Some(scope);
+            }
+        }
+        owner
+    }
+
+    /// Lifts every module item to the top level under its qualified name and rewrites the
+    /// references inside each item to qualified names, checking imports and visibility.
+    ///
+    /// Names that do not resolve are left as written for `validate` to report; run it on the
+    /// result, where every name is unique.
+    pub fn link(&self) -> Result<IR, Vec<Diagnostic>> {
+        let mut linker = Linker { ir: self, alveolus: Vec::new(), diagnostics: Vec::new() };
+        linker.items("", &self.alveolus, &Path::root());
+
+        if linker.diagnostics.is_empty() {
+            Ok(IR { alveolus: linker.alveolus })
+        } else {
+            Err(linker.diagnostics)
+        }
+    }
+}
+
+struct Linker<'a> {
+    ir: &'a IR,
+    alveolus: Vec<Alveolus>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Linker<'_> {
+    fn items(&mut self, scope: &str, items: &[Alveolus], path: &Path) {
+        for (i, item) in items.iter().enumerate() {
+            let path = path.at("alveolus", i);
+            let Alveolus::Module(module) = item else {
+                self.item(scope, item, &path);
+                continue;
+            };
+            let inner = qualify(scope, &module.primor);
+            self.imports(&inner, module, &path);
+            for (k, name) in module.private.iter().enumerate() {
+                if !module.alveolus.iter().any(|alveolus| alveolus.primor() == name) {
+                    self.diagnostics.push(Diagnostic::error(
+                        Code::UnknownPrivate,
+                        path.at("private", k),
+                        format!("module '{}' has no item '{}' to make private", inner, name),
+                    ).with_span(module.span.as_ref()));
+                }
+            }
+            self.items(&inner, &module.alveolus, &path);
+        }
+    }
+
+    fn imports(&mut self, scope: &str, module: &Module, path: &Path) {
+        for (k, import) in module.imports.iter().enumerate() {
+            let (code, message) = if self.ir.lookup(&import.path).is_none() {
+                (Code::UnknownImport, format!("module '{}' imports unknown '{}'", scope, import.path))
+            } else if !self.ir.is_visible(&import.path, scope) {
+                (Code::PrivateName, format!("module '{}' imports '{}', which is private", scope, import.path))
+            } else {
+                continue;
+            };
+            self.diagnostics.push(Diagnostic::error(code, path.at("imports", k), message).with_span(import.span.as_ref()));
+        }
+    }
+
+    fn item(&mut self, scope: &str, item: &Alveolus, path: &Path) {
+        let ir = self.ir;
+        let mut hidden = Vec::new();
+        let mut item = item.clone();
+        rename(&mut item, &mut |name| {
+            let qualified = ir.resolve(scope, name)?;
+            if !ir.is_visible(&qualified, scope) && !hidden.contains(&qualified) {
+                hidden.push(qualified.clone());
+            }
+            Some(qualified)
+        });
+
+        for qualified in hidden {
+            self.diagnostics.push(Diagnostic::error(
+                Code::PrivateName,
+                path.clone(),
+                format!("{} '{}' refers to '{}', which is private", item.kind(), qualify(scope, item.primor()), qualified),
+            ).with_span(item.span()));
+        }
+
+        let primor = qualify(scope, item.primor());
+        match &mut item {
+            Alveolus::Larvie(larvie) => larvie.primor = primor,
+            Alveolus::Enumeration(enumeration) => enumeration.primor = primor,
+            Alveolus::Union(union) => union.primor = primor,
+            Alveolus::Interface(interface) => interface.primor = primor,
+            Alveolus::Mixin(mixin) => mixin.primor = primor,
+            Alveolus::Alias(alias) => alias.primor = primor,
+            Alveolus::Lifecycle(lifecycle) => lifecycle.primor = primor,
+            Alveolus::Event(event) => event.primor = primor,
+            Alveolus::View(view) => view.primor = primor,
+            Alveolus::Module(_) => unreachable!("modules are walked, not linked"),
+        }
+        self.alveolus.push(item);
+    }
+}
+
+/// Replaces every alveolus name `item` refers to for which `f` returns a new one.
+fn rename(item: &mut Alveolus, f: &mut impl FnMut(&str) -> Option<String>) {
+    match item {
+        Alveolus::Larvie(larvie) => {
+            rename_casts(&mut larvie.casts, f);
+            rename_instincts(&mut larvie.instincts, f);
+            for name in larvie.extends.iter_mut().chain(&mut larvie.mixins).chain(&mut larvie.implements) {
+                rename_name(name, f);
+            }
+            for relation in &mut larvie.relations {
+                rename_name(&mut relation.target, f);
+            }
+        }
+        Alveolus::Union(union) => {
+            for variant in &mut union.variants {
+                rename_casts(&mut variant.casts, f);
+            }
+        }
+        Alveolus::Interface(interface) => rename_instincts(&mut interface.instincts, f),
+        Alveolus::Mixin(mixin) => rename_casts(&mut mixin.casts, f),
+        Alveolus::Event(event) => rename_casts(&mut event.casts, f),
+        Alveolus::View(view) => {
+            rename_name(&mut view.source, f);
+            rename_casts(&mut view.computed, f);
+        }
+        Alveolus::Alias(alias) => {
+            alias.flora = rename_flora(&alias.flora, f);
+            rename_seals(&mut alias.seals, f);
+        }
+        Alveolus::Lifecycle(lifecycle) => rename_name(&mut lifecycle.larvie, f),
+        Alveolus::Enumeration(_) | Alveolus::Module(_) => {}
+    }
+}
+
+fn rename_name(name: &mut String, f: &mut impl FnMut(&str) -> Option<String>) {
+    if let Some(renamed) = f(name) {
+        *name = renamed;
+    }
+}
+
+fn rename_casts(casts: &mut [Casts], f: &mut impl FnMut(&str) -> Option<String>) {
+    for cast in casts {
+        cast.flora = rename_flora(&cast.flora, f);
+        rename_seals(&mut cast.seals, f);
+    }
+}
+
+fn rename_instincts(instincts: &mut [Instinct], f: &mut impl FnMut(&str) -> Option<String>) {
+    for instinct in instincts {
+        for param in &mut instinct.params {
+            param.flora = rename_flora(&param.flora, f);
+        }
+        instinct.returns = instinct.returns.as_ref().map(|returns| rename_flora(returns, f));
+        for event in &mut instinct.emits {
+            rename_name(event, f);
+        }
+    }
+}
+
+fn rename_seals(seals: &mut [Seal], f: &mut impl FnMut(&str) -> Option<String>) {
+    for seal in seals {
+        if let Seal::Reference { larvie, .. } = seal {
+            rename_name(larvie, f);
+        }
+    }
+}
+
+fn rename_flora(flora: &Flora, f: &mut impl FnMut(&str) -> Option<String>) -> Flora {
+    flora.rewrite(&mut |flora| match flora {
+        Flora::Bug(name) => f(name).map(Flora::Bug),
+        Flora::Apply { bug, args } => Some(Flora::Apply {
+            bug: f(bug).unwrap_or_else(|| bug.clone()),
+            args: args.iter().map(|arg| rename_flora(arg, &mut *f)).collect(),
+        }),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::fixture::{bug, cast, larvie};
+    use crate::ir::seal::ReferentialAction;
+
+    fn module(primor: &str, alveolus: Vec<Alveolus>, imports: Vec<Import>, private: &[&str]) -> Alveolus {
+        Alveolus::Module(Module {
+            primor: primor.to_string(),
+            alveolus,
+            imports,
+            private: private.iter().map(|name| name.to_string()).collect(),
+            ..Default::default()
+        })
+    }
+
+    fn import(path: &str, alias: Option<&str>) -> Import {
+        Import { path: path.to_string(), alias: alias.map(str::to_string), ..Default::default() }
+    }
+
+    /// Two teams each defining a `User`, with billing reaching into auth.
+    fn teams() -> IR {
+        IR {
+            alveolus: vec![
+                larvie("Country", vec![cast("code", Flora::Str, vec![Seal::Core])]),
+                module("auth", vec![
+                    larvie("User", vec![cast("id", Flora::Uuid, vec![Seal::Core]), cast("secret", bug("Hash"), vec![])]),
+                    larvie("Hash", vec![]),
+                    module("oauth", vec![larvie("Token", vec![cast("hash", bug("Hash"), vec![])])], vec![], &[]),
+                ], vec![], &["Hash"]),
+                module("billing", vec![
+                    larvie("User", vec![
+                        cast("account", bug("Account"), vec![Seal::Reference {
+                            larvie: "Account".to_string(),
+                            cast: "id".to_string(),
+                            on_delete: ReferentialAction::Cascade,
+                            on_update: ReferentialAction::NoAction,
+                        }]),
+                        cast("login", Flora::Optional(Box::new(bug("auth::User"))), vec![]),
+                        cast("country", bug("Country"), vec![]),
+                    ]),
+                    larvie("Account", vec![cast("id", Flora::Uuid, vec![Seal::Core]), cast("owner", bug("Owner"), vec![])]),
+                ], vec![import("auth::User", Some("Owner"))], &[]),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_import_name() {
+        assert_eq!(import("auth::User", None).name(), "User");
+        assert_eq!(import("auth::User", Some("Login")).name(), "Login");
+        assert_eq!(import("Country", None).name(), "Country");
+    }
+
+    #[test]
+    fn test_lookup_and_resolve() {
+        let ir = teams();
+
+        assert_eq!(ir.lookup("auth::oauth::Token").map(Alveolus::kind), Some("larvie"));
+        assert!(ir.lookup("auth").is_none());
+        assert!(ir.lookup("auth::Missing").is_none());
+        assert_eq!(ir.module("auth::oauth").map(|module| module.alveolus.len()), Some(1));
+
+        assert_eq!(ir.resolve("billing", "User").as_deref(), Some("billing::User"));
+        assert_eq!(ir.resolve("billing", "Owner").as_deref(), Some("auth::User"));
+        assert_eq!(ir.resolve("billing", "Country").as_deref(), Some("Country"));
+        assert_eq!(ir.resolve("auth::oauth", "Hash").as_deref(), Some("auth::Hash"));
+        assert_eq!(ir.resolve("", "User"), None);
+        assert_eq!(ir.resolve("", "auth::User").as_deref(), Some("auth::User"));
+    }
+
+    #[test]
+    fn test_visibility() {
+        let ir = teams();
+
+        assert!(ir.is_visible("auth::User", "billing"));
+        assert!(ir.is_visible("auth::Hash", "auth"));
+        assert!(ir.is_visible("auth::Hash", "auth::oauth"));
+        assert!(!ir.is_visible("auth::Hash", "billing"));
+        assert!(!ir.is_visible("auth::Hash", ""));
+    }
+
+    #[test]
+    fn test_link_qualifies_names_and_references() {
+        let linked = teams().link().unwrap();
+
+        let primors: Vec<&str> = linked.alveolus.iter().map(Alveolus::primor).collect();
+        assert_eq!(primors, vec!["Country", "auth::User", "auth::Hash", "auth::oauth::Token", "billing::User", "billing::Account"]);
+
+        let Alveolus::Larvie(ref user) = linked.alveolus[4] else { panic!("expected a larvie") };
+        assert_eq!(user.casts[0].flora, bug("billing::Account"));
+        assert!(matches!(&user.casts[0].seals[0], Seal::Reference { larvie, .. } if larvie == "billing::Account"));
+        assert_eq!(user.casts[1].flora, Flora::Optional(Box::new(bug("auth::User"))));
+        assert_eq!(user.casts[2].flora, bug("Country"));
+        let Alveolus::Larvie(ref account) = linked.alveolus[5] else { panic!("expected a larvie") };
+        assert_eq!(account.casts[1].flora, bug("auth::User"));
+        assert!(linked.validate().is_empty());
+    }
+
+    #[test]
+    fn test_link_reports_imports_and_private_names() {
+        let mut ir = teams();
+        ir.alveolus.push(module("audit", vec![
+            larvie("Entry", vec![cast("hash", bug("auth::Hash"), vec![]), cast("actor", bug("Actor"), vec![])]),
+        ], vec![import("auth::Hash", None), import("auth::Actor", None)], &["Trail"]));
+
+        let diagnostics = ir.link().unwrap_err();
+        let codes: Vec<Code> = diagnostics.iter().map(|d| d.code).collect();
+
+        assert_eq!(codes, vec![Code::PrivateName, Code::UnknownImport, Code::UnknownPrivate, Code::PrivateName]);
+        assert_eq!(diagnostics[0].message, "module 'audit' imports 'auth::Hash', which is private");
+        assert_eq!(diagnostics[1].path.to_string(), "alveolus[3].imports[1]");
+        assert_eq!(diagnostics[2].message, "module 'audit' has no item 'Trail' to make private");
+        assert_eq!(diagnostics[3].message, "larvie 'audit::Entry' refers to 'auth::Hash', which is private");
+        assert_eq!(diagnostics[3].path.to_string(), "alveolus[3].alveolus[0]");
+    }
+}
@@ -0,0 +1,353 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::ir::{
+    IR,
+    alveolus::Alveolus,
+    casts::Casts,
+    diagnostic::{Code, Diagnostic, Path},
+    flora::Flora,
+    instincts::Instinct,
+    larvie::Larvie,
+};
+
+/// How many generic applications may nest while instantiating, so `Page<T>` holding a
+/// `Page<List<T>>` fails instead of expanding forever.
+const MAX_DEPTH: usize = 32;
+
+struct Monomorphizer<'a> {
+    generics: HashMap<&'a str, &'a Larvie>,
+    names: HashSet<&'a str>,
+    /// Generic and arguments behind each instance name, to catch two applications mangling alike.
+    instantiated: HashMap<String, (String, Vec<Flora>)>,
+    instances: Vec<Larvie>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl IR {
+    /// Replaces every applied generic larvie with a concrete instance named after its arguments,
+    /// so `Page<Bird>` becomes a `Bug` pointing at a new `Page_Bird` larvie. Two applications
+    /// whose names mangle alike, such as `Page<List<Int>>` and `Page<List_Int>`, are an error.
+    ///
+    /// Generic definitions are dropped and instances are appended after the remaining alveolus.
+    pub fn monomorphize(&self) -> Result<IR, Vec<Diagnostic>> {
+        let mut mono = Monomorphizer {
+            generics: HashMap::new(),
+            names: self.alveolus.iter().map(Alveolus::primor).collect(),
+            instantiated: HashMap::new(),
+            instances: Vec::new(),
+            diagnostics: Vec::new(),
+        };
+        for alveolus in &self.alveolus {
+            if let Alveolus::Larvie(larvie) = alveolus
+                && !larvie.generics.is_empty()
+            {
+                mono.generics.entry(larvie.primor.as_str()).or_insert(larvie);
+            }
+        }
+
+        let mut alveolus = Vec::new();
+        for (i, item) in self.alveolus.iter().enumerate() {
+            let path = Path::root().at("alveolus", i);
+            match item {
+                Alveolus::Larvie(larvie) if !larvie.generics.is_empty() => {}
+                Alveolus::Larvie(larvie) => alveolus.push(Alveolus::Larvie(mono.larvie(larvie, &path, 0))),
+                Alveolus::Union(union) => {
+                    let mut union = union.clone();
+                    for (j, variant) in union.variants.iter_mut().enumerate() {
+                        variant.casts = mono.casts(&variant.casts, &path.at("variants", j), 0);
+                    }
+                    alveolus.push(Alveolus::Union(union));
+                }
+                Alveolus::Mixin(mixin) => {
+                    let mut mixin = mixin.clone();
+                    mixin.casts = mono.casts(&mixin.casts, &path, 0);
+                    alveolus.push(Alveolus::Mixin(mixin));
+                }
+                Alveolus::Event(event) => {
+                    let mut event = event.clone();
+                    event.casts = mono.casts(&event.casts, &path, 0);
+                    alveolus.push(Alveolus::Event(event));
+                }
+                Alveolus::View(view) => {
+                    let mut view = view.clone();
+                    view.computed = mono.casts(&view.computed, &path, 0);
+                    alveolus.push(Alveolus::View(view));
+                }
+                Alveolus::Interface(interface) => {
+                    let mut interface = interface.clone();
+                    interface.instincts = mono.instincts(&interface.instincts, &path, 0);
+                    alveolus.push(Alveolus::Interface(interface));
+                }
+                Alveolus::Alias(alias) => {
+                    let mut alias = alias.clone();
+                    alias.flora = mono.flora(&alias.flora, &path.key("flora"), 0);
+                    alveolus.push(Alveolus::Alias(alias));
+                }
+                Alveolus::Enumeration(_) | Alveolus::Module(_) | Alveolus::Lifecycle(_) => alveolus.push(item.clone()),
+            }
+        }
+        alveolus.extend(mono.instances.into_iter().map(Alveolus::Larvie));
+
+        if mono.diagnostics.is_empty() {
+            Ok(IR { alveolus })
+        } else {
+            Err(mono.diagnostics)
+        }
+    }
+}
+
+impl Monomorphizer<'_> {
+    fn larvie(&mut self, larvie: &Larvie, path: &Path, depth: usize) -> Larvie {
+        let mut larvie = larvie.clone();
+        larvie.casts = self.casts(&larvie.casts, path, depth);
+        larvie.instincts = self.instincts(&larvie.instincts, path, depth);
+        larvie
+    }
+
+    fn casts(&mut self, casts: &[Casts], path: &Path, depth: usize) -> Vec<Casts> {
+        let mut casts = casts.to_vec();
+        for (i, cast) in casts.iter_mut().enumerate() {
+            cast.flora = self.flora(&cast.flora, &path.at("casts", i).key("flora"), depth);
+        }
+        casts
+    }
+
+    fn instincts(&mut self, instincts: &[Instinct], path: &Path, depth: usize) -> Vec<Instinct> {
+        let mut instincts = instincts.to_vec();
+        for (i, instinct) in instincts.iter_mut().enumerate() {
+            let path = path.at("instincts", i);
+            for (j, param) in instinct.params.iter_mut().enumerate() {
+                param.flora = self.flora(&param.flora, &path.at("params", j).key("flora"), depth);
+            }
+            if let Some(returns) = &instinct.returns {
+                instinct.returns = Some(self.flora(returns, &path.key("returns"), depth));
+            }
+        }
+        instincts
+    }
+
+    fn flora(&mut self, flora: &Flora, path: &Path, depth: usize) -> Flora {
+        flora.rewrite(&mut |node| match node {
+            Flora::Apply { bug, args } => {
+                let args: Vec<Flora> = args.iter().map(|arg| self.flora(arg, path, depth)).collect();
+                Some(Flora::Bug(self.instance(bug, args, path, depth)))
+            }
+            Flora::Param(name) => {
+                self.diagnostics.push(Diagnostic::error(
+                    Code::UnknownTypeParam,
+                    path.clone(),
+                    format!("type parameter '{}' is not bound here", name),
+                ));
+                None
+            }
+            _ => None,
+        })
+    }
+
+    /// Emits the instance of `bug` for concrete `args`, once, and returns its name.
+    fn instance(&mut self, bug: &str, args: Vec<Flora>, path: &Path, depth: usize) -> String {
+        let name = mangle(&Flora::Apply { bug: bug.to_string(), args: args.clone() });
+        let error = |message: String| Diagnostic::error(Code::InvalidGeneric, path.clone(), message);
+        if let Some((other, bound)) = self.instantiated.get(&name) {
+            if other != bug || *bound != args {
+                self.diagnostics.push(error(format!(
+                    "instance '{}' of '{}' with {:?} collides with the one of '{}' with {:?}",
+                    name, bug, args, other, bound
+                )));
+            }
+            return name;
+        }
+
+        let Some(&generic) = self.generics.get(bug) else {
+            self.diagnostics.push(error(format!("'{}' is not a generic larvie", bug)));
+            return name;
+        };
+        if generic.generics.len() != args.len() {
+            self.diagnostics.push(error(format!("'{}' takes {} type arguments, found {}", bug, generic.generics.len(), args.len())));
+            return name;
+        }
+        if depth >= MAX_DEPTH {
+            self.diagnostics.push(error(format!("instantiating '{}' does not terminate", bug)));
+            return name;
+        }
+        if self.names.contains(name.as_str()) {
+            self.diagnostics.push(error(format!("instance '{}' collides with an alveolus of the same name", name)));
+            return name;
+        }
+        self.instantiated.insert(name.clone(), (bug.to_string(), args.clone()));
+
+        let bindings: HashMap<&str, &Flora> = generic.generics.iter().map(String::as_str).zip(&args).collect();
+        let mut instance = generic.clone();
+        instance.primor = name.clone();
+        instance.generics = Vec::new();
+        instance.map_floras(&mut |flora| match flora {
+            Flora::Param(param) => bindings.get(param.as_str()).map(|&bound| bound.clone()),
+            _ => None,
+        });
+        let instance = self.larvie(&instance, path, depth + 1);
+        self.instances.push(instance);
+        name
+    }
+}
+
+/// Name of a concrete instance: `Page<Bird>` is `Page_Bird`, `Page<List<Int>>` is `Page_List_Int`.
+fn mangle(flora: &Flora) -> String {
+    match flora {
+        Flora::Bug(name) | Flora::Param(name) => name.clone(),
+        Flora::Decimal { precision, scale } => format!("Decimal_{}_{}", precision, scale),
+        Flora::List(inner) => format!("List_{}", mangle(inner)),
+        Flora::Optional(inner) => format!("Optional_{}", mangle(inner)),
+        Flora::Map(key, value) => format!("Map_{}_{}", mangle(key), mangle(value)),
+        Flora::Apply { bug, args } => {
+            let mut name = bug.clone();
+            for arg in args {
+                name.push('_');
+                name.push_str(&mangle(arg));
+            }
+            name
+        }
+        scalar => format!("{:?}", scalar),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(bug: &str, args: Vec<Flora>) -> Flora {
+        Flora::Apply { bug: bug.to_string(), args }
+    }
+
+    fn param(name: &str) -> Flora {
+        Flora::Param(name.to_string())
+    }
+
+    fn page() -> Alveolus {
+        Alveolus::Larvie(Larvie {
+            primor: "Page".to_string(),
+            generics: vec!["T".to_string()],
+            casts: vec![
+                Casts::new("items", Flora::List(Box::new(param("T")))),
+                Casts::new("total", Flora::U32),
+            ],
+            instincts: vec![Instinct { echo: "first".to_string(), returns: Some(Flora::Optional(Box::new(param("T")))), ..Default::default() }],
+            ..Default::default()
+        })
+    }
+
+    fn feed(casts: Vec<(&str, Flora)>) -> Alveolus {
+        Alveolus::Larvie(Larvie {
+            primor: "Feed".to_string(),
+            casts: casts
+                .into_iter()
+                .map(|(primor, flora)| Casts::new(primor, flora))
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_mangle() {
+        assert_eq!(mangle(&apply("Page", vec![Flora::Bug("Bird".to_string())])), "Page_Bird");
+        assert_eq!(mangle(&apply("Page", vec![Flora::List(Box::new(Flora::Int))])), "Page_List_Int");
+        assert_eq!(mangle(&apply("Pair", vec![Flora::Str, Flora::Decimal { precision: 8, scale: 2 }])), "Pair_Str_Decimal_8_2");
+    }
+
+    #[test]
+    fn test_monomorphize_emits_concrete_instances() {
+        let bird = Flora::Bug("Bird".to_string());
+        let ir = IR {
+            alveolus: vec![
+                page(),
+                feed(vec![
+                    ("birds", apply("Page", vec![bird.clone()])),
+                    ("again", Flora::Optional(Box::new(apply("Page", vec![bird.clone()])))),
+                    ("nested", apply("Page", vec![apply("Page", vec![Flora::Int])])),
+                ]),
+                Alveolus::Larvie(Larvie { primor: "Bird".to_string(), ..Default::default() }),
+            ],
+        };
+
+        let mono = ir.monomorphize().unwrap();
+        let primors: Vec<&str> = mono.alveolus.iter().map(Alveolus::primor).collect();
+
+        assert_eq!(primors, vec!["Feed", "Bird", "Page_Bird", "Page_Int", "Page_Page_Int"]);
+        let Alveolus::Larvie(ref feed) = mono.alveolus[0] else { panic!("expected a larvie") };
+        assert_eq!(feed.casts[0].flora, Flora::Bug("Page_Bird".to_string()));
+        assert_eq!(feed.casts[1].flora, Flora::Optional(Box::new(Flora::Bug("Page_Bird".to_string()))));
+        assert_eq!(feed.casts[2].flora, Flora::Bug("Page_Page_Int".to_string()));
+
+        let Alveolus::Larvie(ref page_bird) = mono.alveolus[2] else { panic!("expected a larvie") };
+        assert!(page_bird.generics.is_empty());
+        assert_eq!(page_bird.casts[0].flora, Flora::List(Box::new(bird.clone())));
+        assert_eq!(page_bird.instincts[0].returns, Some(Flora::Optional(Box::new(bird))));
+
+        let Alveolus::Larvie(ref page_page) = mono.alveolus[4] else { panic!("expected a larvie") };
+        assert_eq!(page_page.casts[0].flora, Flora::List(Box::new(Flora::Bug("Page_Int".to_string()))));
+        assert!(mono.validate().is_empty());
+    }
+
+    #[test]
+    fn test_monomorphize_errors() {
+        let ir = IR {
+            alveolus: vec![
+                page(),
+                feed(vec![
+                    ("a", apply("Page", vec![Flora::Int, Flora::Str])),
+                    ("b", apply("Feed", vec![Flora::Int])),
+                    ("c", param("T")),
+                ]),
+            ],
+        };
+
+        let diagnostics = ir.monomorphize().unwrap_err();
+        let codes: Vec<Code> = diagnostics.iter().map(|d| d.code).collect();
+
+        assert_eq!(codes, vec![Code::InvalidGeneric, Code::InvalidGeneric, Code::UnknownTypeParam]);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[1].casts[0].flora");
+    }
+
+    #[test]
+    fn test_monomorphize_rejects_colliding_instances() {
+        let ir = IR {
+            alveolus: vec![
+                page(),
+                feed(vec![
+                    ("ints", apply("Page", vec![Flora::List(Box::new(Flora::Int))])),
+                    ("lists", apply("Page", vec![Flora::Bug("List_Int".to_string())])),
+                ]),
+                Alveolus::Larvie(Larvie { primor: "List_Int".to_string(), ..Default::default() }),
+            ],
+        };
+
+        let diagnostics = ir.monomorphize().unwrap_err();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, Code::InvalidGeneric);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[1].casts[1].flora");
+        assert_eq!(
+            diagnostics[0].message,
+            "instance 'Page_List_Int' of 'Page' with [Bug(\"List_Int\")] collides with the one of 'Page' with [List(Int)]"
+        );
+    }
+
+    #[test]
+    fn test_monomorphize_rejects_infinite_expansion() {
+        let ir = IR {
+            alveolus: vec![
+                Alveolus::Larvie(Larvie {
+                    primor: "Tree".to_string(),
+                    generics: vec!["T".to_string()],
+                    casts: vec![Casts::new("children", apply("Tree", vec![Flora::List(Box::new(param("T")))]))],
+                    ..Default::default()
+                }),
+                feed(vec![("tree", apply("Tree", vec![Flora::Int]))]),
+            ],
+        };
+
+        let diagnostics = ir.monomorphize().unwrap_err();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "instantiating 'Tree' does not terminate");
+    }
+}
@@ -0,0 +1,281 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ir::{
+    IR,
+    alveolus::Alveolus,
+    attribute::Attributes,
+    casts::Casts,
+    constraint::Constraint,
+    diagnostic::{Code, Diagnostic, Path},
+    larvie::Larvie,
+    seal::{ReferentialAction, Seal},
+    symbol::SymbolTable,
+    span::Span,
+};
+
+/// A navigable link from the declaring larvie to `target`; `primor` is the navigation name on
+/// the declaring side and `inverse` the name of the way back, declared on `target`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Relation {
+    pub primor: String,
+    pub target: String,
+    pub cardinality: Cardinality,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inverse: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+/// How many rows sit on each side, read from the declaring larvie towards the target.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Cardinality {
+    OneToOne,
+    OneToMany,
+    ManyToOne,
+    ManyToMany,
+}
+
+impl Relation {
+    /// A relation without an inverse. The cardinality has no sensible default, so there is no
+    /// `Default`; set the inverse with `Relation { inverse, ..Relation::new(primor, target, cardinality) }`.
+    pub fn new(primor: impl Into<String>, target: impl Into<String>, cardinality: Cardinality) -> Relation {
+        Relation {
+            primor: primor.into(),
+            target: target.into(),
+            cardinality,
+            inverse: None,
+            doc: None,
+            attributes: Attributes::new(),
+            span: None,
+        }
+    }
+}
+
+impl Cardinality {
+    /// The cardinality of the same relation read from the target side.
+    pub fn inverse(self) -> Cardinality {
+        match self {
+            Cardinality::OneToMany => Cardinality::ManyToOne,
+            Cardinality::ManyToOne => Cardinality::OneToMany,
+            same => same,
+        }
+    }
+}
+
+impl IR {
+    /// Adds a junction larvie for every many-to-many relation, as relational targets need.
+    ///
+    /// The junction for `Bird.tags` is `Bird_tags`, holding one cast per side (`bird_id`,
+    /// `tag_id`) that references that side's key cast with `Cascade`, together forming its key.
+    /// A relation and its declared inverse share a single junction. A `Seal::Reference` points
+    /// at one cast, so both sides need a single-cast key; a composite key is an error.
+    pub fn lower_relations(&self) -> Result<IR, Vec<Diagnostic>> {
+        let symbols = self.symbols();
+        let mut diagnostics = Vec::new();
+        let mut lowered: HashSet<(&str, &str)> = HashSet::new();
+        let mut junctions = Vec::new();
+
+        for (i, alveolus) in self.alveolus.iter().enumerate() {
+            let Alveolus::Larvie(source) = alveolus else { continue };
+            for (j, relation) in source.relations.iter().enumerate() {
+                if relation.cardinality != Cardinality::ManyToMany || lowered.contains(&(source.primor.as_str(), relation.primor.as_str())) {
+                    continue;
+                }
+                let path = Path::root().at("alveolus", i).at("relations", j);
+                match junction(&symbols, source, relation) {
+                    Ok(junction) => junctions.push(junction),
+                    Err(message) => {
+                        diagnostics.push(Diagnostic::error(Code::InvalidRelation, path, message).with_span(relation.span.as_ref()));
+                        continue;
+                    }
+                }
+                if let Some(inverse) = &relation.inverse {
+                    lowered.insert((relation.target.as_str(), inverse.as_str()));
+                }
+            }
+        }
+
+        for junction in &junctions {
+            if symbols.first(&junction.primor).is_some() {
+                diagnostics.push(Diagnostic::error(
+                    Code::InvalidRelation,
+                    Path::root(),
+                    format!("junction '{}' collides with an alveolus of the same name", junction.primor),
+                ));
+            }
+        }
+
+        if !diagnostics.is_empty() {
+            return Err(diagnostics);
+        }
+        let mut ir = self.clone();
+        ir.alveolus.extend(junctions.into_iter().map(Alveolus::Larvie));
+        Ok(ir)
+    }
+}
+
+fn junction(symbols: &SymbolTable, source: &Larvie, relation: &Relation) -> Result<Larvie, String> {
+    let Some(Alveolus::Larvie(target)) = symbols.first(&relation.target).map(|handle| symbols.get(handle)) else {
+        return Err(format!("relation '{}' targets unknown larvie '{}'", relation.primor, relation.target));
+    };
+
+    // A larvie related to itself needs distinct prefixes for its two sides.
+    let source_prefix = source.primor.to_lowercase();
+    let target_prefix = if source.primor == target.primor { relation.primor.clone() } else { target.primor.to_lowercase() };
+
+    let mut casts = Vec::new();
+    for (side, prefix) in [(source, &source_prefix), (target, &target_prefix)] {
+        let primor = match side.key()[..] {
+            [] => return Err(format!("larvie '{}' needs a key to join through relation '{}'", side.primor, relation.primor)),
+            [primor] => primor,
+            _ => return Err(format!("larvie '{}' has a composite key, which relation '{}' cannot reference", side.primor, relation.primor)),
+        };
+        let Some(cast) = side.cast(primor) else {
+            return Err(format!("key cast '{}.{}' does not exist", side.primor, primor));
+        };
+        casts.push(Casts {
+            seals: vec![
+                Seal::Vital,
+                Seal::Reference {
+                    larvie: side.primor.clone(),
+                    cast: primor.to_string(),
+                    on_delete: ReferentialAction::Cascade,
+                    on_update: ReferentialAction::Cascade,
+                },
+            ],
+            span: relation.span.clone(),
+            ..Casts::new(format!("{}_{}", prefix, primor), cast.flora.clone())
+        });
+    }
+
+    Ok(Larvie {
+        primor: format!("{}_{}", source.primor, relation.primor),
+        constraints: vec![Constraint::key(casts.iter().map(|cast| cast.primor.clone()).collect())],
+        casts,
+        span: relation.span.clone(),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::flora::Flora;
+    use serde_json;
+
+    fn keyed(primor: &str, relations: Vec<Relation>) -> Alveolus {
+        Alveolus::Larvie(Larvie {
+            primor: primor.to_string(),
+            casts: vec![Casts { seals: vec![Seal::Core], ..Casts::new("id", Flora::Uuid) }],
+            relations,
+            ..Default::default()
+        })
+    }
+
+    fn relation(primor: &str, target: &str, cardinality: Cardinality, inverse: Option<&str>) -> Relation {
+        Relation { inverse: inverse.map(str::to_string), ..Relation::new(primor, target, cardinality) }
+    }
+
+    #[test]
+    fn test_cardinality_inverse() {
+        assert_eq!(Cardinality::OneToMany.inverse(), Cardinality::ManyToOne);
+        assert_eq!(Cardinality::ManyToOne.inverse(), Cardinality::OneToMany);
+        assert_eq!(Cardinality::OneToOne.inverse(), Cardinality::OneToOne);
+        assert_eq!(Cardinality::ManyToMany.inverse(), Cardinality::ManyToMany);
+    }
+
+    #[test]
+    fn test_relation_json_format() {
+        let tags = relation("tags", "Tag", Cardinality::ManyToMany, Some("birds"));
+
+        let json = serde_json::to_string(&tags).unwrap();
+
+        assert_eq!(json, "{\"primor\":\"tags\",\"target\":\"Tag\",\"cardinality\":\"ManyToMany\",\"inverse\":\"birds\"}");
+        assert_eq!(serde_json::from_str::<Relation>(&json).unwrap(), tags);
+    }
+
+    #[test]
+    fn test_lower_many_to_many_into_junction() {
+        let ir = IR {
+            alveolus: vec![
+                keyed("Bird", vec![
+                    relation("tags", "Tag", Cardinality::ManyToMany, Some("birds")),
+                    relation("nest", "Nest", Cardinality::ManyToOne, None),
+                ]),
+                keyed("Tag", vec![relation("birds", "Bird", Cardinality::ManyToMany, Some("tags"))]),
+                keyed("Nest", vec![]),
+            ],
+        };
+
+        let lowered = ir.lower_relations().unwrap();
+
+        assert_eq!(lowered.alveolus.len(), 4);
+        let Alveolus::Larvie(ref junction) = lowered.alveolus[3] else { panic!("expected a larvie") };
+        assert_eq!(junction.primor, "Bird_tags");
+        assert_eq!(junction.key(), vec!["bird_id", "tag_id"]);
+        assert_eq!(junction.casts[1].flora, Flora::Uuid);
+        assert_eq!(junction.casts[1].seals[1], Seal::Reference {
+            larvie: "Tag".to_string(),
+            cast: "id".to_string(),
+            on_delete: ReferentialAction::Cascade,
+            on_update: ReferentialAction::Cascade,
+        });
+        assert!(lowered.validate().is_empty());
+    }
+
+    #[test]
+    fn test_lower_self_relation() {
+        let ir = IR { alveolus: vec![keyed("Bird", vec![relation("friends", "Bird", Cardinality::ManyToMany, None)])] };
+
+        let lowered = ir.lower_relations().unwrap();
+
+        let Alveolus::Larvie(ref junction) = lowered.alveolus[1] else { panic!("expected a larvie") };
+        assert_eq!(junction.key(), vec!["bird_id", "friends_id"]);
+    }
+
+    #[test]
+    fn test_lower_relations_errors() {
+        let ir = IR {
+            alveolus: vec![
+                keyed("Bird", vec![
+                    relation("tags", "Tag", Cardinality::ManyToMany, None),
+                    relation("trees", "Tree", Cardinality::ManyToMany, None),
+                ]),
+                Alveolus::Larvie(Larvie { primor: "Tag".to_string(), ..Default::default() }),
+            ],
+        };
+
+        let diagnostics = ir.lower_relations().unwrap_err();
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].message, "larvie 'Tag' needs a key to join through relation 'tags'");
+        assert_eq!(diagnostics[1].path.to_string(), "alveolus[0].relations[1]");
+    }
+
+    #[test]
+    fn test_lower_relations_rejects_composite_keys() {
+        let ir = IR {
+            alveolus: vec![
+                keyed("Bird", vec![relation("slots", "Slot", Cardinality::ManyToMany, None)]),
+                Alveolus::Larvie(Larvie {
+                    primor: "Slot".to_string(),
+                    casts: vec![Casts::new("hive", Flora::Uuid), Casts::new("number", Flora::U16)],
+                    constraints: vec![Constraint::key(vec!["hive".to_string(), "number".to_string()])],
+                    ..Default::default()
+                }),
+            ],
+        };
+
+        let diagnostics = ir.lower_relations().unwrap_err();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, Code::InvalidRelation);
+        assert_eq!(diagnostics[0].message, "larvie 'Slot' has a composite key, which relation 'slots' cannot reference");
+    }
+}
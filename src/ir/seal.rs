@@ -1,10 +1,46 @@
 use serde::{Deserialize, Serialize};
 
+use crate::ir::literal::Literal;
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Seal {
     Vital,  // NotNull
     Core,   // PrimaryKey
     Root,   // Unique
+    Default(Literal),  // DEFAULT
+    Range {            // CHECK (min <= value <= max)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<Literal>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<Literal>,
+    },
+    Length {           // CHECK (min <= length(value) <= max)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<u64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<u64>,
+    },
+    Pattern(String),   // regular expression the value must match
+    Check(String),     // free-form CHECK expression
+    Reference {        // FOREIGN KEY
+        larvie: String,
+        cast: String,
+        #[serde(default)]
+        on_delete: ReferentialAction,
+        #[serde(default)]
+        on_update: ReferentialAction,
+    },
+}
+
+/// What happens to a referencing cast when the referenced row is deleted or its key updated.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ReferentialAction {
+    #[default]
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+    SetDefault,
 }
 
 #[cfg(test)]
@@ -63,4 +99,50 @@ mod tests {
         let deserialized: Vec<Seal> = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized.len(), 0);
     }
+
+    #[test]
+    fn test_parameterized_seal_json_format() {
+        let expected = [
+            (Seal::Default(Literal::Int(100)), "{\"Default\":{\"Int\":100}}"),
+            (
+                Seal::Range { min: Some(Literal::Int(0)), max: Some(Literal::Int(1000)) },
+                "{\"Range\":{\"min\":{\"Int\":0},\"max\":{\"Int\":1000}}}",
+            ),
+            (Seal::Length { min: None, max: Some(64) }, "{\"Length\":{\"max\":64}}"),
+            (Seal::Pattern("^[a-z]+$".to_string()), "{\"Pattern\":\"^[a-z]+$\"}"),
+            (Seal::Check("energy % 2 = 0".to_string()), "{\"Check\":\"energy % 2 = 0\"}"),
+        ];
+
+        for (seal, json) in expected {
+            assert_eq!(serde_json::to_string(&seal).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Seal>(json).unwrap(), seal);
+        }
+    }
+
+    #[test]
+    fn test_reference_seal_json_format() {
+        let seal = Seal::Reference {
+            larvie: "Nest".to_string(),
+            cast: "id".to_string(),
+            on_delete: ReferentialAction::Cascade,
+            on_update: ReferentialAction::NoAction,
+        };
+
+        let json = serde_json::to_string(&seal).unwrap();
+
+        assert_eq!(json, "{\"Reference\":{\"larvie\":\"Nest\",\"cast\":\"id\",\"on_delete\":\"Cascade\",\"on_update\":\"NoAction\"}}");
+        assert_eq!(serde_json::from_str::<Seal>(&json).unwrap(), seal);
+    }
+
+    #[test]
+    fn test_reference_seal_actions_default_to_no_action() {
+        let seal: Seal = serde_json::from_str("{\"Reference\":{\"larvie\":\"Nest\",\"cast\":\"id\"}}").unwrap();
+
+        assert_eq!(seal, Seal::Reference {
+            larvie: "Nest".to_string(),
+            cast: "id".to_string(),
+            on_delete: ReferentialAction::NoAction,
+            on_update: ReferentialAction::NoAction,
+        });
+    }
 }
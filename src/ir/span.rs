@@ -0,0 +1,70 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Where a node came from in SC-DSL source: byte range plus the line/column it starts at.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Span {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub col: usize,
+}
+
+impl Span {
+    /// Smallest span covering both `self` and `other`.
+    pub fn to(&self, other: &Span) -> Span {
+        let first = if self.start <= other.start { self } else { other };
+        Span {
+            file: self.file.clone(),
+            start: first.start,
+            end: self.end.max(other.end),
+            line: first.line,
+            col: first.col,
+        }
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}", file, self.line, self.col),
+            None => write!(f, "{}:{}", self.line, self.col),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_span_display() {
+        let span = Span { file: None, start: 0, end: 3, line: 2, col: 5 };
+
+        assert_eq!(span.to_string(), "2:5");
+        assert_eq!(Span { file: Some("bird.sc".to_string()), ..span }.to_string(), "bird.sc:2:5");
+    }
+
+    #[test]
+    fn test_span_to() {
+        let left = Span { file: None, start: 4, end: 8, line: 1, col: 5 };
+        let right = Span { file: None, start: 20, end: 23, line: 3, col: 1 };
+
+        assert_eq!(left.to(&right), Span { file: None, start: 4, end: 23, line: 1, col: 5 });
+        assert_eq!(right.to(&left), left.to(&right));
+    }
+
+    #[test]
+    fn test_span_serialization_omits_missing_file() {
+        let span = Span { file: None, start: 0, end: 3, line: 1, col: 1 };
+
+        let json = serde_json::to_string(&span).unwrap();
+
+        assert_eq!(json, "{\"start\":0,\"end\":3,\"line\":1,\"col\":1}");
+        assert_eq!(serde_json::from_str::<Span>(&json).unwrap(), span);
+    }
+}
@@ -0,0 +1,318 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ir::{
+    IR,
+    alveolus::Alveolus,
+    casts::Casts,
+    diagnostic::{Code, Diagnostic, Path},
+    flora::Flora,
+    instincts::Instinct,
+    larvie::Larvie,
+    seal::Seal,
+    span::Span,
+};
+
+/// Identifies a definition by its position in `IR.alveolus`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct Handle(pub usize);
+
+/// Every top-level name of an IR and the definitions carrying it.
+///
+/// Passes that look up many names (`validate`, `flatten`, `lower_relations`, the dependency
+/// graph) build one table up front. One-shot queries such as `IR::alias` or `IR::view` keep
+/// scanning `IR.alveolus`, which costs the same as building the table for a single name.
+///
+/// Module items are not listed; build the table from the result of `IR::link`. To follow
+/// a name through modules and imports before linking, use `IR::resolve` and `IR::lookup`.
+#[derive(Debug, Clone)]
+pub struct SymbolTable<'a> {
+    ir: &'a IR,
+    names: HashMap<&'a str, Vec<Handle>>,
+}
+
+/// A name written in the IR, bound to the definition it refers to.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Reference {
+    pub path: Path,
+    pub name: String,
+    pub handle: Handle,
+}
+
+/// The outcome of `IR::bind_names`: the symbol table and every reference it resolved.
+#[derive(Debug, Clone)]
+pub struct Bindings<'a> {
+    pub symbols: SymbolTable<'a>,
+    pub references: Vec<Reference>,
+}
+
+impl<'a> SymbolTable<'a> {
+    pub fn new(ir: &'a IR) -> SymbolTable<'a> {
+        let mut names: HashMap<&str, Vec<Handle>> = HashMap::new();
+        for (i, alveolus) in ir.alveolus.iter().enumerate() {
+            names.entry(alveolus.primor()).or_default().push(Handle(i));
+        }
+        SymbolTable { ir, names }
+    }
+
+    /// The definition named `name`, unless there is none or more than one.
+    pub fn unique(&self, name: &str) -> Option<Handle> {
+        match self.names.get(name).map(Vec::as_slice) {
+            Some(&[handle]) => Some(handle),
+            _ => None,
+        }
+    }
+
+    /// The first definition named `name`. Passes that report duplicate definitions themselves,
+    /// like `validate`, use it to keep checking past them.
+    pub fn first(&self, name: &str) -> Option<Handle> {
+        self.candidates(name).first().copied()
+    }
+
+    /// Every definition named `name`, in declaration order.
+    pub fn candidates(&self, name: &str) -> &[Handle] {
+        self.names.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn get(&self, handle: Handle) -> &'a Alveolus {
+        &self.ir.alveolus[handle.0]
+    }
+
+    /// Lowercase kind of the definition behind `handle`, as `Alveolus::kind` names it.
+    pub fn kind(&self, handle: Handle) -> &'static str {
+        self.get(handle).kind()
+    }
+
+    /// The larvie named `name`, if the name is unambiguous and belongs to one.
+    pub fn larvie(&self, name: &str) -> Option<&'a Larvie> {
+        match self.get(self.unique(name)?) {
+            Alveolus::Larvie(larvie) => Some(larvie),
+            _ => None,
+        }
+    }
+
+    /// The definition a `Flora::Bug` or `Flora::Apply` refers to.
+    pub fn flora(&self, flora: &Flora) -> Option<Handle> {
+        match flora {
+            Flora::Bug(name) | Flora::Apply { bug: name, .. } => self.unique(name),
+            _ => None,
+        }
+    }
+}
+
+impl Bindings<'_> {
+    /// The handles of the references written at `path`.
+    pub fn at(&self, path: &Path) -> Vec<Handle> {
+        self.references.iter().filter(|reference| &reference.path == path).map(|reference| reference.handle).collect()
+    }
+}
+
+impl IR {
+    pub fn symbols(&self) -> SymbolTable<'_> {
+        SymbolTable::new(self)
+    }
+
+    /// Binds every alveolus name written in the IR (floras, references, parents, mixins,
+    /// interfaces, relation and lifecycle targets, emitted events, view sources) to a handle.
+    ///
+    /// Names with no definition, or with several, are reported instead. Like `SymbolTable`, this
+    /// works on linked IR; `IR::resolve` answers for a single name inside a module tree.
+    pub fn bind_names(&self) -> Result<Bindings<'_>, Vec<Diagnostic>> {
+        let symbols = self.symbols();
+        let mut sites = Sites::default();
+        for (i, alveolus) in self.alveolus.iter().enumerate() {
+            sites.alveolus(alveolus, &Path::root().at("alveolus", i));
+        }
+
+        let mut references = Vec::new();
+        let mut diagnostics = Vec::new();
+        for (path, name, span) in sites.0 {
+            match symbols.candidates(name) {
+                [handle] => references.push(Reference { path, name: name.to_string(), handle: *handle }),
+                [] => diagnostics.push(Diagnostic::error(Code::UnresolvedName, path, format!("name '{}' does not refer to any alveolus", name)).with_span(span)),
+                handles => {
+                    let places: Vec<String> = handles.iter().map(|handle| format!("alveolus[{}]", handle.0)).collect();
+                    let message = format!("name '{}' is defined more than once: {}", name, places.join(", "));
+                    diagnostics.push(Diagnostic::error(Code::AmbiguousName, path, message).with_span(span));
+                }
+            }
+        }
+
+        if diagnostics.is_empty() {
+            Ok(Bindings { symbols, references })
+        } else {
+            Err(diagnostics)
+        }
+    }
+}
+
+/// The places where an alveolus name is written, with the span of the node holding it.
+#[derive(Default)]
+struct Sites<'a>(Vec<(Path, &'a str, Option<&'a Span>)>);
+
+impl<'a> Sites<'a> {
+    fn alveolus(&mut self, alveolus: &'a Alveolus, path: &Path) {
+        let span = alveolus.span();
+        match alveolus {
+            Alveolus::Larvie(larvie) => {
+                self.casts(&larvie.casts, &path.key("casts"));
+                self.instincts(&larvie.instincts, &path.key("instincts"), span);
+                if let Some(parent) = &larvie.extends {
+                    self.0.push((path.key("extends"), parent, span));
+                }
+                for (i, name) in larvie.mixins.iter().enumerate() {
+                    self.0.push((path.at("mixins", i), name, span));
+                }
+                for (i, name) in larvie.implements.iter().enumerate() {
+                    self.0.push((path.at("implements", i), name, span));
+                }
+                for (i, relation) in larvie.relations.iter().enumerate() {
+                    self.0.push((path.at("relations", i).key("target"), &relation.target, relation.span.as_ref().or(span)));
+                }
+            }
+            Alveolus::Union(union) => {
+                for (i, variant) in union.variants.iter().enumerate() {
+                    self.casts(&variant.casts, &path.at("variants", i).key("casts"));
+                }
+            }
+            Alveolus::Interface(interface) => self.instincts(&interface.instincts, &path.key("instincts"), span),
+            Alveolus::Mixin(mixin) => self.casts(&mixin.casts, &path.key("casts")),
+            Alveolus::Alias(alias) => {
+                self.flora(&alias.flora, &path.key("flora"), span);
+                self.seals(&alias.seals, path, span);
+            }
+            Alveolus::Lifecycle(lifecycle) => self.0.push((path.key("larvie"), &lifecycle.larvie, span)),
+            Alveolus::Event(event) => self.casts(&event.casts, &path.key("casts")),
+            Alveolus::View(view) => {
+                self.0.push((path.key("source"), &view.source, span));
+                self.casts(&view.computed, &path.key("computed"));
+            }
+            Alveolus::Enumeration(_) | Alveolus::Module(_) => {}
+        }
+    }
+
+    fn casts(&mut self, casts: &'a [Casts], path: &Path) {
+        for (i, cast) in casts.iter().enumerate() {
+            let path = path.index(i);
+            self.flora(&cast.flora, &path.key("flora"), cast.span.as_ref());
+            self.seals(&cast.seals, &path, cast.span.as_ref());
+        }
+    }
+
+    fn seals(&mut self, seals: &'a [Seal], path: &Path, span: Option<&'a Span>) {
+        for (i, seal) in seals.iter().enumerate() {
+            if let Seal::Reference { larvie, .. } = seal {
+                self.0.push((path.at("seals", i), larvie, span));
+            }
+        }
+    }
+
+    fn instincts(&mut self, instincts: &'a [Instinct], path: &Path, owner: Option<&'a Span>) {
+        for (i, instinct) in instincts.iter().enumerate() {
+            let path = path.index(i);
+            let span = instinct.span.as_ref().or(owner);
+            for (j, param) in instinct.params.iter().enumerate() {
+                self.flora(&param.flora, &path.at("params", j).key("flora"), param.span.as_ref().or(span));
+            }
+            if let Some(returns) = &instinct.returns {
+                self.flora(returns, &path.key("returns"), span);
+            }
+            for (j, event) in instinct.emits.iter().enumerate() {
+                self.0.push((path.at("emits", j), event, span));
+            }
+        }
+    }
+
+    fn flora(&mut self, flora: &'a Flora, path: &Path, span: Option<&'a Span>) {
+        for name in flora.bugs() {
+            self.0.push((path.clone(), name, span));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{enumeration::Enumeration, fixture::cast, seal::ReferentialAction};
+
+    fn aviary() -> IR {
+        IR {
+            alveolus: vec![
+                Alveolus::Larvie(Larvie {
+                    primor: "Bird".to_string(),
+                    casts: vec![
+                        cast("id", Flora::Uuid, vec![Seal::Core]),
+                        cast("nest", Flora::Uuid, vec![Seal::Reference {
+                            larvie: "Nest".to_string(),
+                            cast: "id".to_string(),
+                            on_delete: ReferentialAction::Cascade,
+                            on_update: ReferentialAction::NoAction,
+                        }]),
+                        cast("colors", Flora::Map(Box::new(Flora::Bug("Color".to_string())), Box::new(Flora::Bug("Nest".to_string()))), vec![]),
+                    ],
+                    ..Default::default()
+                }),
+                Alveolus::Larvie(Larvie { primor: "Nest".to_string(), casts: vec![cast("id", Flora::Uuid, vec![Seal::Core])], ..Default::default() }),
+                Alveolus::Enumeration(Enumeration { primor: "Color".to_string(), ..Default::default() }),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_symbol_table() {
+        let ir = aviary();
+        let symbols = ir.symbols();
+
+        let nest = symbols.unique("Nest").unwrap();
+        assert_eq!(nest, Handle(1));
+        assert_eq!(symbols.kind(nest), "larvie");
+        assert_eq!(symbols.get(nest).primor(), "Nest");
+        assert_eq!(symbols.flora(&Flora::Bug("Color".to_string())), Some(Handle(2)));
+        assert_eq!(symbols.flora(&Flora::Str), None);
+        assert_eq!(symbols.larvie("Bird").map(|bird| bird.casts.len()), Some(3));
+        assert!(symbols.larvie("Color").is_none());
+        assert!(symbols.unique("Tree").is_none());
+    }
+
+    #[test]
+    fn test_bind_names() {
+        let ir = aviary();
+
+        let bindings = ir.bind_names().unwrap();
+
+        let bound: Vec<(String, &str, usize)> = bindings
+            .references
+            .iter()
+            .map(|reference| (reference.path.to_string(), reference.name.as_str(), reference.handle.0))
+            .collect();
+        assert_eq!(bound, vec![
+            ("alveolus[0].casts[1].seals[0]".to_string(), "Nest", 1),
+            ("alveolus[0].casts[2].flora".to_string(), "Color", 2),
+            ("alveolus[0].casts[2].flora".to_string(), "Nest", 1),
+        ]);
+        let flora = Path::root().at("alveolus", 0).at("casts", 2).key("flora");
+        assert_eq!(bindings.at(&flora), vec![Handle(2), Handle(1)]);
+    }
+
+    #[test]
+    fn test_bind_names_reports_unresolved_and_ambiguous() {
+        let mut ir = aviary();
+        ir.alveolus.push(Alveolus::Enumeration(Enumeration { primor: "Nest".to_string(), ..Default::default() }));
+        let Alveolus::Larvie(ref mut bird) = ir.alveolus[0] else { panic!("expected a larvie") };
+        bird.implements.push("Flying".to_string());
+
+        let symbols = ir.symbols();
+        assert_eq!(symbols.unique("Nest"), None);
+        assert_eq!(symbols.first("Nest"), Some(Handle(1)));
+        assert_eq!(symbols.candidates("Nest"), &[Handle(1), Handle(3)]);
+
+        let diagnostics = ir.bind_names().unwrap_err();
+        let codes: Vec<Code> = diagnostics.iter().map(|d| d.code).collect();
+
+        assert_eq!(codes, vec![Code::AmbiguousName, Code::AmbiguousName, Code::UnresolvedName]);
+        assert_eq!(diagnostics[0].message, "name 'Nest' is defined more than once: alveolus[1], alveolus[3]");
+        assert_eq!(diagnostics[2].message, "name 'Flying' does not refer to any alveolus");
+        assert_eq!(diagnostics[2].path.to_string(), "alveolus[0].implements[0]");
+    }
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ir::{attribute::Attributes, casts::Casts, span::Span};
+
+/// A tagged union: a value is exactly one of the variants, each carrying its own casts.
+///
+/// The IR form is `{"Union":{"primor":"Outcome","variants":[{"primor":"Landed","casts":[…]}]}}`;
+/// a variant without casts is a plain tag.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Union {
+    pub primor: String,
+    pub variants: Vec<Variant>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Variant {
+    pub primor: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub casts: Vec<Casts>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+impl Union {
+    pub fn variant(&self, primor: &str) -> Option<&Variant> {
+        self.variants.iter().find(|variant| variant.primor == primor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{flora::Flora, seal::Seal};
+    use serde_json;
+
+    fn outcome() -> Union {
+        Union {
+            primor: "Outcome".to_string(),
+            variants: vec![
+                Variant {
+                    primor: "Landed".to_string(),
+                    casts: vec![Casts { seals: vec![Seal::Vital], ..Casts::new("on", Flora::Bug("Tree".to_string())) }],
+                    ..Default::default()
+                },
+                Variant { primor: "Lost".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_union_variant_lookup() {
+        let outcome = outcome();
+
+        assert_eq!(outcome.variant("Landed").unwrap().casts.len(), 1);
+        assert!(outcome.variant("Lost").unwrap().casts.is_empty());
+        assert!(outcome.variant("Crashed").is_none());
+    }
+
+    #[test]
+    fn test_union_json_format() {
+        let outcome = outcome();
+
+        let json = serde_json::to_string(&outcome).unwrap();
+
+        assert_eq!(
+            json,
+            "{\"primor\":\"Outcome\",\"variants\":[{\"primor\":\"Landed\",\"casts\":[{\"primor\":\"on\",\"flora\":{\"Bug\":\"Tree\"},\"seals\":[\"Vital\"]}]},{\"primor\":\"Lost\"}]}"
+        );
+        assert_eq!(serde_json::from_str::<Union>(&json).unwrap(), outcome);
+    }
+}
@@ -0,0 +1,2581 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::mem;
+
+use crate::ir::{
+    IR,
+    alias::Alias,
+    alveolus::Alveolus,
+    body::{self, Expr, Scope, Stmt, TypeError},
+    casts::Casts,
+    constraint::Constraint,
+    enumeration::Enumeration,
+    event::Event,
+    interface::Interface,
+    diagnostic::{Code, Diagnostic, Path},
+    flora::Flora,
+    graph::DependencyKind,
+    instincts::Instinct,
+    larvie::Larvie,
+    lifecycle::Lifecycle,
+    literal::Literal,
+    mixin::Mixin,
+    seal::{ReferentialAction, Seal},
+    span::Span,
+    symbol::{Handle, SymbolTable},
+    union::Union,
+    view::View,
+};
+
+/// Names visible to the checks: the first alveolus defined under each name, and the type
+/// parameters of the larvie being checked.
+#[derive(Clone, Copy)]
+struct Context<'a> {
+    ir: &'a IR,
+    symbols: &'a SymbolTable<'a>,
+    generics: &'a [String],
+}
+
+impl<'a> Context<'a> {
+    fn alveolus(&self, name: &str) -> Option<&'a Alveolus> {
+        self.symbols.first(name).map(|handle| self.symbols.get(handle))
+    }
+
+    fn larvie(&self, name: &str) -> Option<&'a Larvie> {
+        match self.alveolus(name) {
+            Some(Alveolus::Larvie(larvie)) => Some(larvie),
+            _ => None,
+        }
+    }
+
+    /// The instinct named `echo` that `larvie` declares or inherits through `extends`, so checks
+    /// on an unflattened IR see the same instincts as on its flattened form.
+    fn instinct<'b>(&self, larvie: &'b Larvie, echo: &str) -> Option<&'b Instinct>
+    where
+        'a: 'b,
+    {
+        let mut seen = HashSet::new();
+        let mut next = Some(larvie);
+        while let Some(current) = next
+            && seen.insert(current.primor.as_str())
+        {
+            if let Some(instinct) = current.instincts.iter().find(|instinct| instinct.echo == echo) {
+                return Some(instinct);
+            }
+            next = current.extends.as_deref().and_then(|parent| self.larvie(parent));
+        }
+        None
+    }
+
+    fn enumeration(&self, name: &str) -> Option<&'a Enumeration> {
+        match self.alveolus(name) {
+            Some(Alveolus::Enumeration(enumeration)) => Some(enumeration),
+            _ => None,
+        }
+    }
+}
+
+impl IR {
+    /// Runs every semantic check over the IR and returns what it found, errors and warnings alike.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let symbols = self.symbols();
+
+        for (i, alveolus) in self.alveolus.iter().enumerate() {
+            if let Some(Handle(first)) = symbols.first(alveolus.primor()).filter(|&first| first != Handle(i)) {
+                let code = match alveolus {
+                    Alveolus::Larvie(_) => Code::DuplicateLarvie,
+                    Alveolus::Enumeration(_) => Code::DuplicateEnumeration,
+                    Alveolus::Union(_) => Code::DuplicateUnion,
+                    Alveolus::Interface(_) => Code::DuplicateInterface,
+                    Alveolus::Mixin(_) => Code::DuplicateMixin,
+                    Alveolus::Alias(_) => Code::DuplicateAlias,
+                    Alveolus::Module(_) => Code::DuplicateModule,
+                    Alveolus::Lifecycle(_) => Code::DuplicateLifecycle,
+                    Alveolus::Event(_) => Code::DuplicateEvent,
+                    Alveolus::View(_) => Code::DuplicateView,
+                };
+                diagnostics.push(Diagnostic::error(
+                    code,
+                    Path::root().at("alveolus", i),
+                    format!("{} '{}' is already defined at alveolus[{}]", alveolus.kind(), alveolus.primor(), first),
+                ).with_span(alveolus.span()));
+            }
+        }
+
+        let cx = Context { ir: self, symbols: &symbols, generics: &[] };
+
+        for (i, alveolus) in self.alveolus.iter().enumerate() {
+            let path = Path::root().at("alveolus", i);
+            match alveolus {
+                Alveolus::Larvie(larvie) => validate_larvie(larvie, &cx, &path, &mut diagnostics),
+                Alveolus::Enumeration(enumeration) => validate_enumeration(enumeration, &path, &mut diagnostics),
+                Alveolus::Union(union) => validate_union(union, &cx, &path, &mut diagnostics),
+                Alveolus::Interface(interface) => validate_interface(interface, &cx, &path, &mut diagnostics),
+                Alveolus::Mixin(mixin) => validate_mixin(mixin, &cx, &path, &mut diagnostics),
+                Alveolus::Alias(alias) => validate_alias(alias, &cx, &path, &mut diagnostics),
+                // Module items are checked once `IR::link` has lifted them to the top level.
+                Alveolus::Module(_) => {}
+                Alveolus::Lifecycle(lifecycle) => validate_lifecycle(lifecycle, &cx, &path, &mut diagnostics),
+                Alveolus::Event(event) => validate_event(event, &cx, &path, &mut diagnostics),
+                Alveolus::View(view) => validate_view(view, &cx, &path, &mut diagnostics),
+            }
+        }
+        validate_dependencies(self, &mut diagnostics);
+
+        diagnostics
+    }
+}
+
+/// Reports alveolus that contain themselves by value. Cycles made of aliases alone are left to
+/// `validate_alias`, which reports them as `AliasCycle`.
+fn validate_dependencies(ir: &IR, diagnostics: &mut Vec<Diagnostic>) {
+    let graph = ir.dependencies();
+    for cycle in graph.embedding_cycles() {
+        if cycle.iter().all(|handle| matches!(ir.alveolus[handle.0], Alveolus::Alias(_))) {
+            continue;
+        }
+        let first = &ir.alveolus[cycle[0].0];
+        let Some(edge) = graph.dependencies(cycle[0]).find(|edge| edge.kind == DependencyKind::Embedding && cycle.contains(&edge.to)) else {
+            continue;
+        };
+        let message = if cycle.len() == 1 {
+            format!("{} '{}' embeds itself by value; put it behind Optional, List or a reference", first.kind(), first.primor())
+        } else {
+            let names: Vec<String> = cycle.iter().map(|handle| format!("'{}'", ir.alveolus[handle.0].primor())).collect();
+            format!("{} embed each other by value; put one of them behind Optional, List or a reference", names.join(", "))
+        };
+        diagnostics.push(Diagnostic::error(Code::EmbeddingCycle, edge.path.clone(), message).with_span(first.span()));
+    }
+}
+
+fn validate_enumeration(enumeration: &Enumeration, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    if enumeration.members.is_empty() {
+        diagnostics.push(Diagnostic::error(
+            Code::InvalidMember,
+            path.clone(),
+            format!("enumeration '{}' declares no members", enumeration.primor),
+        ).with_span(enumeration.span.as_ref()));
+    }
+
+    let mut names = HashSet::new();
+    let mut values: Vec<&Literal> = Vec::new();
+    for (i, member) in enumeration.members.iter().enumerate() {
+        let path = path.at("members", i);
+        let span = member.span.as_ref().or(enumeration.span.as_ref());
+        if !names.insert(member.primor.as_str()) {
+            diagnostics.push(Diagnostic::error(
+                Code::DuplicateMember,
+                path.clone(),
+                format!("member '{}' is declared more than once in enumeration '{}'", member.primor, enumeration.primor),
+            ).with_span(span));
+        }
+
+        let Some(value) = &member.value else { continue };
+        let path = path.key("value");
+        if !matches!(value, Literal::Int(_) | Literal::Str(_)) {
+            diagnostics.push(Diagnostic::error(
+                Code::InvalidMember,
+                path,
+                format!("member '{}' of enumeration '{}' has value {:?}; values must be Int or Str", member.primor, enumeration.primor, value),
+            ).with_span(span));
+        } else if values.contains(&value) {
+            diagnostics.push(Diagnostic::error(
+                Code::DuplicateMember,
+                path,
+                format!("value {:?} is used by more than one member of enumeration '{}'", value, enumeration.primor),
+            ).with_span(span));
+        } else if values.first().is_some_and(|first| mem::discriminant(*first) != mem::discriminant(value)) {
+            diagnostics.push(Diagnostic::error(
+                Code::InvalidMember,
+                path,
+                format!("enumeration '{}' mixes Int and Str member values", enumeration.primor),
+            ).with_span(span));
+        } else {
+            values.push(value);
+        }
+    }
+}
+
+fn validate_union(union: &Union, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    if union.variants.is_empty() {
+        diagnostics.push(Diagnostic::error(
+            Code::InvalidVariant,
+            path.clone(),
+            format!("union '{}' declares no variants", union.primor),
+        ).with_span(union.span.as_ref()));
+    }
+
+    let mut variants = HashSet::new();
+    for (i, variant) in union.variants.iter().enumerate() {
+        let path = path.at("variants", i);
+        if !variants.insert(variant.primor.as_str()) {
+            diagnostics.push(Diagnostic::error(
+                Code::DuplicateVariant,
+                path.clone(),
+                format!("variant '{}' is declared more than once in union '{}'", variant.primor, union.primor),
+            ).with_span(variant.span.as_ref().or(union.span.as_ref())));
+        }
+
+        let mut casts = HashSet::new();
+        for (j, cast) in variant.casts.iter().enumerate() {
+            let path = path.at("casts", j);
+            if !casts.insert(cast.primor.as_str()) {
+                diagnostics.push(Diagnostic::error(
+                    Code::DuplicateCast,
+                    path.clone(),
+                    format!("cast '{}' is declared more than once in variant '{}.{}'", cast.primor, union.primor, variant.primor),
+                ).with_span(cast.span.as_ref()));
+            }
+            reject_key_seals(cast, &format!("variant '{}.{}'", union.primor, variant.primor), &path, diagnostics);
+            validate_casts(cast, cx, &path, diagnostics);
+        }
+    }
+}
+
+/// Reports `Core` and `Root` seals on a cast of a union variant or an event. Payloads are
+/// messages rather than rows, so there is nothing for a key or uniqueness seal to hold on to.
+fn reject_key_seals(cast: &Casts, owner: &str, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    for (k, seal) in cast.seals.iter().enumerate() {
+        if matches!(seal, Seal::Core | Seal::Root) {
+            diagnostics.push(Diagnostic::error(
+                Code::InvalidSeal,
+                path.at("seals", k),
+                format!("{:?} seal does not apply to cast '{}' of {}", seal, cast.primor, owner),
+            ).with_span(cast.span.as_ref()));
+        }
+    }
+}
+
+/// Checks that a lifecycle runs over a larvie, that its transitions join declared states through
+/// instincts of that larvie, and that every state is reachable and either terminal or left again.
+fn validate_lifecycle(lifecycle: &Lifecycle, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    let error = |code: Code, path: Path, message: String| Diagnostic::error(code, path, message).with_span(lifecycle.span.as_ref());
+
+    let larvie = match cx.alveolus(&lifecycle.larvie) {
+        Some(Alveolus::Larvie(larvie)) => Some(larvie),
+        found => {
+            let message = match found {
+                Some(other) => format!("lifecycle '{}' runs over {} '{}'; only larvies have lifecycles", lifecycle.primor, other.kind(), lifecycle.larvie),
+                None => format!("lifecycle '{}' runs over unknown larvie '{}'", lifecycle.primor, lifecycle.larvie),
+            };
+            diagnostics.push(error(Code::InvalidLifecycle, path.key("larvie"), message));
+            None
+        }
+    };
+
+    if lifecycle.states.is_empty() {
+        diagnostics.push(error(Code::InvalidLifecycle, path.clone(), format!("lifecycle '{}' declares no states", lifecycle.primor)));
+        return;
+    }
+    let mut states = HashSet::new();
+    for (i, state) in lifecycle.states.iter().enumerate() {
+        if !states.insert(state.primor.as_str()) {
+            diagnostics.push(error(
+                Code::DuplicateState,
+                path.at("states", i),
+                format!("state '{}' is declared more than once in lifecycle '{}'", state.primor, lifecycle.primor),
+            ));
+        }
+    }
+    let initial = states.contains(lifecycle.initial.as_str());
+    if !initial {
+        diagnostics.push(error(
+            Code::UnknownState,
+            path.key("initial"),
+            format!("initial state '{}' is not a state of lifecycle '{}'", lifecycle.initial, lifecycle.primor),
+        ));
+    }
+
+    let mut exits = HashSet::new();
+    for (i, transition) in lifecycle.transitions.iter().enumerate() {
+        let path = path.at("transitions", i);
+        for state in [&transition.from, &transition.to] {
+            if !states.contains(state.as_str()) {
+                diagnostics.push(error(
+                    Code::UnknownState,
+                    path.clone(),
+                    format!("transition '{}' uses unknown state '{}' of lifecycle '{}'", transition.instinct, state, lifecycle.primor),
+                ));
+            }
+        }
+        if let Some(larvie) = larvie
+            && !larvie.instincts.iter().any(|instinct| instinct.echo == transition.instinct)
+        {
+            diagnostics.push(error(
+                Code::UnknownInstinct,
+                path.key("instinct"),
+                format!("larvie '{}' has no instinct '{}' for lifecycle '{}'", larvie.primor, transition.instinct, lifecycle.primor),
+            ));
+        }
+        if !exits.insert((transition.from.as_str(), transition.instinct.as_str())) {
+            diagnostics.push(error(
+                Code::InvalidLifecycle,
+                path.clone(),
+                format!("instinct '{}' leaves state '{}' through more than one transition", transition.instinct, transition.from),
+            ));
+        }
+    }
+
+    let reachable = lifecycle.reachable();
+    for (i, state) in lifecycle.states.iter().enumerate() {
+        if initial && !reachable.contains(state.primor.as_str()) {
+            diagnostics.push(error(
+                Code::UnreachableState,
+                path.at("states", i),
+                format!("state '{}' of lifecycle '{}' cannot be reached from '{}'", state.primor, lifecycle.primor, lifecycle.initial),
+            ));
+        }
+        if !state.terminal && !lifecycle.transitions.iter().any(|transition| transition.from == state.primor) {
+            diagnostics.push(error(
+                Code::DeadState,
+                path.at("states", i),
+                format!("state '{}' of lifecycle '{}' has no way out and is not terminal", state.primor, lifecycle.primor),
+            ));
+        }
+    }
+}
+
+fn validate_interface(interface: &Interface, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    let mut instincts = HashSet::new();
+    for (i, instinct) in interface.instincts.iter().enumerate() {
+        let path = path.at("instincts", i);
+        if !instincts.insert(instinct.echo.as_str()) {
+            diagnostics.push(Diagnostic::error(
+                Code::DuplicateInstinct,
+                path.clone(),
+                format!("instinct '{}' is declared more than once in interface '{}'", instinct.echo, interface.primor),
+            ).with_span(instinct.span.as_ref()));
+        }
+        if !instinct.body.is_empty() {
+            diagnostics.push(Diagnostic::error(
+                Code::InvalidInterface,
+                path.key("body"),
+                format!("instinct '{}' of interface '{}' declares a body; interfaces only carry signatures", instinct.echo, interface.primor),
+            ).with_span(instinct.span.as_ref()));
+        }
+        validate_instinct(None, instinct, cx, &path, diagnostics);
+    }
+}
+
+fn validate_mixin(mixin: &Mixin, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    let mut casts = HashSet::new();
+    for (i, cast) in mixin.casts.iter().enumerate() {
+        let path = path.at("casts", i);
+        if !casts.insert(cast.primor.as_str()) {
+            diagnostics.push(Diagnostic::error(
+                Code::DuplicateCast,
+                path.clone(),
+                format!("cast '{}' is declared more than once in mixin '{}'", cast.primor, mixin.primor),
+            ).with_span(cast.span.as_ref()));
+        }
+        validate_casts(cast, cx, &path, diagnostics);
+    }
+}
+
+fn validate_event(event: &Event, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    let mut casts = HashSet::new();
+    for (i, cast) in event.casts.iter().enumerate() {
+        let path = path.at("casts", i);
+        if !casts.insert(cast.primor.as_str()) {
+            diagnostics.push(Diagnostic::error(
+                Code::DuplicateCast,
+                path.clone(),
+                format!("cast '{}' is declared more than once in event '{}'", cast.primor, event.primor),
+            ).with_span(cast.span.as_ref()));
+        }
+        reject_key_seals(cast, &format!("event '{}'", event.primor), &path, diagnostics);
+        validate_casts(cast, cx, &path, diagnostics);
+    }
+}
+
+/// Checks that a view projects a larvie through existing casts, exposes each name once and
+/// computes the rest from the source's casts.
+fn validate_view(view: &View, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    let source = match cx.alveolus(&view.source) {
+        Some(Alveolus::Larvie(larvie)) => larvie,
+        found => {
+            let message = match found {
+                Some(other) => format!("view '{}' projects {} '{}'; only larvies can be projected", view.primor, other.kind(), view.source),
+                None => format!("view '{}' projects unknown larvie '{}'", view.primor, view.source),
+            };
+            diagnostics.push(Diagnostic::error(Code::InvalidView, path.key("source"), message).with_span(view.span.as_ref()));
+            return;
+        }
+    };
+    if view.selects.is_empty() && view.computed.is_empty() {
+        diagnostics.push(Diagnostic::error(
+            Code::InvalidView,
+            path.clone(),
+            format!("view '{}' exposes no casts", view.primor),
+        ).with_span(view.span.as_ref()));
+    }
+
+    let mut names = HashSet::new();
+    let duplicate = |primor: &str, path: Path, span: Option<&Span>| {
+        Diagnostic::error(Code::DuplicateCast, path, format!("cast '{}' appears more than once in view '{}'", primor, view.primor)).with_span(span)
+    };
+    for (i, selection) in view.selects.iter().enumerate() {
+        let path = path.at("selects", i);
+        let span = selection.span.as_ref().or(view.span.as_ref());
+        if source.cast(&selection.cast).is_none() {
+            diagnostics.push(Diagnostic::error(
+                Code::UnknownCast,
+                path.clone(),
+                format!("larvie '{}' has no cast '{}' for view '{}'", source.primor, selection.cast, view.primor),
+            ).with_span(span));
+        }
+        if !names.insert(selection.primor()) {
+            diagnostics.push(duplicate(selection.primor(), path, span));
+        }
+    }
+
+    for (i, cast) in view.computed.iter().enumerate() {
+        let path = path.at("computed", i);
+        if !names.insert(cast.primor.as_str()) {
+            diagnostics.push(duplicate(&cast.primor, path.clone(), cast.span.as_ref()));
+        }
+        validate_casts(cast, cx, &path, diagnostics);
+        match &cast.computed {
+            Some(expr) => validate_computed(source, cast, expr, cx, &path, diagnostics),
+            None => diagnostics.push(Diagnostic::error(
+                Code::InvalidView,
+                path,
+                format!("cast '{}' of view '{}' is neither selected nor computed", cast.primor, view.primor),
+            ).with_span(cast.span.as_ref())),
+        }
+    }
+}
+
+fn validate_alias(alias: &Alias, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    let owner = format!("alias '{}'", alias.primor);
+    validate_flora(&alias.flora, &owner, alias.span.as_ref(), cx, &path.key("flora"), diagnostics);
+
+    // Expansion only leaves an alias behind when it loops back on itself.
+    let underlying = cx.ir.underlying(&alias.flora);
+    if underlying.bugs().iter().any(|name| cx.ir.alias(name).is_some()) {
+        diagnostics.push(Diagnostic::error(
+            Code::AliasCycle,
+            path.key("flora"),
+            format!("alias '{}' refers back to itself", alias.primor),
+        ).with_span(alias.span.as_ref()));
+        return;
+    }
+
+    let resolved = Casts { seals: alias.seals.clone(), span: alias.span.clone(), ..Casts::new(alias.primor.clone(), underlying) };
+    for (i, seal) in alias.seals.iter().enumerate() {
+        let path = path.at("seals", i);
+        if matches!(seal, Seal::Core | Seal::Root | Seal::Reference { .. }) {
+            diagnostics.push(Diagnostic::error(
+                Code::InvalidSeal,
+                path,
+                format!("{:?} seal belongs on a cast, not on alias '{}'", seal, alias.primor),
+            ).with_span(alias.span.as_ref()));
+            continue;
+        }
+        validate_seal(seal, &resolved, &owner, cx, &path, diagnostics);
+    }
+}
+
+/// Checks that `extends` names a larvie without looping back, and that every mixin exists.
+fn validate_extensions(larvie: &Larvie, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    if let Some(parent) = &larvie.extends {
+        let path = path.key("extends");
+        match cx.alveolus(parent) {
+            Some(Alveolus::Larvie(_)) => {
+                let mut seen = HashSet::from([larvie.primor.as_str()]);
+                let mut next = cx.larvie(parent);
+                while let Some(ancestor) = next {
+                    if !seen.insert(ancestor.primor.as_str()) {
+                        if ancestor.primor == larvie.primor {
+                            diagnostics.push(Diagnostic::error(
+                                Code::InheritanceCycle,
+                                path.clone(),
+                                format!("larvie '{}' extends itself through '{}'", larvie.primor, parent),
+                            ).with_span(larvie.span.as_ref()));
+                        }
+                        break;
+                    }
+                    next = ancestor.extends.as_deref().and_then(|name| cx.larvie(name));
+                }
+            }
+            found => {
+                let message = match found {
+                    Some(other) => format!("larvie '{}' extends {} '{}'; only larvies can be extended", larvie.primor, other.kind(), parent),
+                    None => format!("larvie '{}' extends unknown larvie '{}'", larvie.primor, parent),
+                };
+                diagnostics.push(Diagnostic::error(Code::UnknownParent, path, message).with_span(larvie.span.as_ref()));
+            }
+        }
+    }
+
+    for (i, name) in larvie.mixins.iter().enumerate() {
+        let message = match cx.alveolus(name) {
+            Some(Alveolus::Mixin(_)) => continue,
+            Some(other) => format!("larvie '{}' includes {} '{}', which is not a mixin", larvie.primor, other.kind(), name),
+            None => format!("larvie '{}' includes unknown mixin '{}'", larvie.primor, name),
+        };
+        diagnostics.push(Diagnostic::error(Code::UnknownMixin, path.at("mixins", i), message).with_span(larvie.span.as_ref()));
+    }
+}
+
+/// Checks that `larvie` provides every instinct of the interfaces it implements, with the same signature.
+fn validate_implements(larvie: &Larvie, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    for (i, name) in larvie.implements.iter().enumerate() {
+        let path = path.at("implements", i);
+        let interface = match cx.alveolus(name) {
+            Some(Alveolus::Interface(interface)) => interface,
+            found => {
+                let message = match found {
+                    Some(other) => format!("larvie '{}' implements {} '{}', which is not an interface", larvie.primor, other.kind(), name),
+                    None => format!("larvie '{}' implements unknown interface '{}'", larvie.primor, name),
+                };
+                diagnostics.push(Diagnostic::error(Code::UnknownInterface, path, message).with_span(larvie.span.as_ref()));
+                continue;
+            }
+        };
+
+        for required in &interface.instincts {
+            match cx.instinct(larvie, &required.echo) {
+                None => diagnostics.push(Diagnostic::error(
+                    Code::MissingInstinct,
+                    path.clone(),
+                    format!("larvie '{}' does not provide instinct '{}' required by interface '{}'", larvie.primor, required.echo, name),
+                ).with_span(larvie.span.as_ref())),
+                Some(provided) if !provided.same_signature(required) => diagnostics.push(Diagnostic::error(
+                    Code::SignatureMismatch,
+                    path.clone(),
+                    format!("instinct '{}' of larvie '{}' does not match its signature in interface '{}'", provided.echo, larvie.primor, name),
+                ).with_span(provided.span.as_ref())),
+                Some(_) => {}
+            }
+        }
+    }
+}
+
+fn validate_larvie(larvie: &Larvie, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    let cx = &Context { generics: &larvie.generics, ..*cx };
+    for (i, generic) in larvie.generics.iter().enumerate() {
+        if larvie.generics[..i].contains(generic) {
+            diagnostics.push(Diagnostic::error(
+                Code::InvalidGeneric,
+                path.at("generics", i),
+                format!("type parameter '{}' is declared more than once in larvie '{}'", generic, larvie.primor),
+            ).with_span(larvie.span.as_ref()));
+        }
+    }
+
+    let mut casts = HashSet::new();
+    let mut cores = Vec::new();
+
+    for (i, cast) in larvie.casts.iter().enumerate() {
+        let path = path.at("casts", i);
+        if !casts.insert(cast.primor.as_str()) {
+            diagnostics.push(Diagnostic::error(
+                Code::DuplicateCast,
+                path.clone(),
+                format!("cast '{}' is declared more than once in larvie '{}'", cast.primor, larvie.primor),
+            ).with_span(cast.span.as_ref()));
+        }
+        if cast.seals.contains(&Seal::Core) {
+            cores.push(cast.primor.as_str());
+        }
+        validate_casts(cast, cx, &path, diagnostics);
+        if let Some(expr) = &cast.computed {
+            validate_computed(larvie, cast, expr, cx, &path, diagnostics);
+        }
+    }
+
+    let keys = larvie.constraints.iter().filter(|constraint| matches!(constraint, Constraint::Key { .. })).count();
+    if cores.len() > 1 {
+        diagnostics.push(Diagnostic::error(
+            Code::MultipleCore,
+            path.clone(),
+            format!("larvie '{}' has more than one Core cast: {}", larvie.primor, cores.join(", ")),
+        ).with_span(larvie.span.as_ref()));
+    } else if keys > 1 || (keys == 1 && !cores.is_empty()) {
+        diagnostics.push(Diagnostic::error(
+            Code::MultipleCore,
+            path.clone(),
+            format!("larvie '{}' declares more than one primary key", larvie.primor),
+        ).with_span(larvie.span.as_ref()));
+    }
+
+    for (i, constraint) in larvie.constraints.iter().enumerate() {
+        validate_key_casts(larvie, constraint.casts(), &path.at("constraints", i), diagnostics);
+    }
+
+    let mut indexes = HashSet::new();
+    for (i, index) in larvie.indexes.iter().enumerate() {
+        let path = path.at("indexes", i);
+        if !indexes.insert(index.primor.as_str()) {
+            diagnostics.push(Diagnostic::error(
+                Code::DuplicateIndex,
+                path.clone(),
+                format!("index '{}' is declared more than once in larvie '{}'", index.primor, larvie.primor),
+            ).with_span(larvie.span.as_ref()));
+        }
+        let casts: Vec<String> = index.parts.iter().map(|part| part.cast.clone()).collect();
+        validate_key_casts(larvie, &casts, &path, diagnostics);
+    }
+
+    let mut instincts = HashSet::new();
+    for (i, instinct) in larvie.instincts.iter().enumerate() {
+        if !instincts.insert(instinct.echo.as_str()) {
+            diagnostics.push(Diagnostic::error(
+                Code::DuplicateInstinct,
+                path.at("instincts", i),
+                format!("instinct '{}' is declared more than once in larvie '{}'", instinct.echo, larvie.primor),
+            ).with_span(instinct.span.as_ref()));
+        }
+        validate_instinct(Some(larvie), instinct, cx, &path.at("instincts", i), diagnostics);
+    }
+
+    validate_relations(larvie, cx, path, diagnostics);
+    validate_extensions(larvie, cx, path, diagnostics);
+    validate_implements(larvie, cx, path, diagnostics);
+}
+
+/// Checks that relations have unique names next to the casts, target larvies, and agree
+/// with the relation their `inverse` names on the target.
+fn validate_relations(larvie: &Larvie, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    let mut relations = HashSet::new();
+    for (i, relation) in larvie.relations.iter().enumerate() {
+        let path = path.at("relations", i);
+        let duplicate = if !relations.insert(relation.primor.as_str()) {
+            Some(format!("relation '{}' is declared more than once in larvie '{}'", relation.primor, larvie.primor))
+        } else if larvie.cast(&relation.primor).is_some() {
+            Some(format!("relation '{}' clashes with a cast of the same name in larvie '{}'", relation.primor, larvie.primor))
+        } else {
+            None
+        };
+        if let Some(message) = duplicate {
+            diagnostics.push(Diagnostic::error(Code::DuplicateRelation, path.clone(), message).with_span(relation.span.as_ref()));
+        }
+
+        let target = match cx.alveolus(&relation.target) {
+            Some(Alveolus::Larvie(target)) => target,
+            found => {
+                let message = match found {
+                    Some(other) => format!("relation '{}' targets {} '{}'; only larvies can be related", relation.primor, other.kind(), relation.target),
+                    None => format!("relation '{}' targets unknown larvie '{}'", relation.primor, relation.target),
+                };
+                diagnostics.push(Diagnostic::error(Code::InvalidRelation, path, message).with_span(relation.span.as_ref()));
+                continue;
+            }
+        };
+
+        let Some(inverse) = &relation.inverse else { continue };
+        let message = if target.cast(inverse).is_some() {
+            format!("inverse '{}' of relation '{}' clashes with a cast of larvie '{}'", inverse, relation.primor, target.primor)
+        } else {
+            match target.relations.iter().find(|back| &back.primor == inverse) {
+                None => format!("inverse '{}' of relation '{}' is not a relation of larvie '{}'", inverse, relation.primor, target.primor),
+                Some(back) if back.target != larvie.primor || back.inverse.as_deref() != Some(relation.primor.as_str()) => {
+                    format!("relation '{}.{}' does not point back to '{}.{}'", target.primor, inverse, larvie.primor, relation.primor)
+                }
+                Some(back) if back.cardinality != relation.cardinality.inverse() => format!(
+                    "relation '{}.{}' is {:?} but its inverse '{}.{}' is {:?}",
+                    larvie.primor, relation.primor, relation.cardinality, target.primor, inverse, back.cardinality
+                ),
+                Some(_) => continue,
+            }
+        };
+        diagnostics.push(Diagnostic::error(Code::InvalidRelation, path.key("inverse"), message).with_span(relation.span.as_ref()));
+    }
+}
+
+/// Checks that a computed cast reads only casts of `larvie`, yields its flora, carries no
+/// storage seal and, when it is one of them, does not depend on itself.
+fn validate_computed(larvie: &Larvie, cast: &Casts, expr: &Expr, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    let invalid = |path: Path, message: String| Diagnostic::error(Code::InvalidComputed, path, message).with_span(cast.span.as_ref());
+
+    for (i, seal) in cast.seals.iter().enumerate() {
+        let name = match seal {
+            Seal::Core => "Core",
+            Seal::Root => "Root",
+            Seal::Default(_) => "Default",
+            Seal::Reference { .. } => "Reference",
+            _ => continue,
+        };
+        diagnostics.push(invalid(path.at("seals", i), format!("computed cast '{}' is never stored and cannot be {}", cast.primor, name)));
+    }
+
+    let path = path.key("computed");
+    if !reads_casts_only(expr) {
+        diagnostics.push(invalid(path, format!("computed cast '{}' may only read sibling casts and literals", cast.primor)));
+        return;
+    }
+
+    let scope = Scope { ir: cx.ir, larvie, params: &[] };
+    if let Err(error) = expr.expect(&cx.ir.underlying(&cast.flora), &scope) {
+        let code = match error {
+            TypeError::Unknown(_) => Code::UnknownSymbol,
+            TypeError::Mismatch(_) => Code::TypeMismatch,
+        };
+        diagnostics.push(Diagnostic::error(code, path, error.to_string()).with_span(cast.span.as_ref()));
+        return;
+    }
+
+    // A view's computed casts read the source larvie but are not part of it.
+    if larvie.cast(&cast.primor) != Some(cast) {
+        return;
+    }
+    let mut seen = HashSet::new();
+    let mut pending = expr.casts();
+    while let Some(name) = pending.pop() {
+        if name == cast.primor {
+            diagnostics.push(invalid(path, format!("computed cast '{}' depends on itself", cast.primor)));
+            return;
+        }
+        if seen.insert(name)
+            && let Some(computed) = larvie.cast(name).and_then(|sibling| sibling.computed.as_ref())
+        {
+            pending.extend(computed.casts());
+        }
+    }
+}
+
+fn reads_casts_only(expr: &Expr) -> bool {
+    match expr {
+        Expr::Literal(_) | Expr::Cast(_) => true,
+        Expr::Param(_) | Expr::Call { .. } => false,
+        Expr::Unary { operand, .. } => reads_casts_only(operand),
+        Expr::Binary { lhs, rhs, .. } => reads_casts_only(lhs) && reads_casts_only(rhs),
+    }
+}
+
+/// Checks an instinct's signature, and its body when it belongs to a `larvie`.
+fn validate_instinct(larvie: Option<&Larvie>, instinct: &Instinct, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    let mut params = HashSet::new();
+    for (i, param) in instinct.params.iter().enumerate() {
+        let path = path.at("params", i);
+        if !params.insert(param.primor.as_str()) {
+            diagnostics.push(Diagnostic::error(
+                Code::DuplicateParam,
+                path.clone(),
+                format!("parameter '{}' is declared more than once in instinct '{}'", param.primor, instinct.echo),
+            ).with_span(param.span.as_ref().or(instinct.span.as_ref())));
+        }
+        let owner = format!("parameter '{}' of instinct '{}'", param.primor, instinct.echo);
+        validate_flora(&param.flora, &owner, param.span.as_ref().or(instinct.span.as_ref()), cx, &path.key("flora"), diagnostics);
+    }
+
+    if let Some(returns) = &instinct.returns {
+        let owner = format!("instinct '{}'", instinct.echo);
+        validate_flora(returns, &owner, instinct.span.as_ref(), cx, &path.key("returns"), diagnostics);
+    }
+
+    for (i, fault) in instinct.faults.iter().enumerate() {
+        if instinct.faults[..i].contains(fault) {
+            diagnostics.push(Diagnostic::warning(
+                Code::DuplicateFault,
+                path.at("faults", i),
+                format!("fault '{}' is repeated on instinct '{}'", fault, instinct.echo),
+            ).with_span(instinct.span.as_ref()));
+        }
+    }
+
+    for (i, name) in instinct.emits.iter().enumerate() {
+        let message = match cx.alveolus(name) {
+            Some(Alveolus::Event(_)) => continue,
+            Some(other) => format!("instinct '{}' emits {} '{}', which is not an event", instinct.echo, other.kind(), name),
+            None => format!("instinct '{}' emits unknown event '{}'", instinct.echo, name),
+        };
+        diagnostics.push(Diagnostic::error(Code::UnknownEvent, path.at("emits", i), message).with_span(instinct.span.as_ref()));
+    }
+
+    if let Some(larvie) = larvie {
+        let scope = Scope { ir: cx.ir, larvie, params: &instinct.params };
+        validate_body(&instinct.body, instinct, &scope, &path.key("body"), diagnostics);
+    }
+}
+
+/// Type-checks the statements of an instinct body against its larvie and signature.
+fn validate_body(body: &[Stmt], instinct: &Instinct, scope: &Scope, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    for (i, stmt) in body.iter().enumerate() {
+        let path = path.index(i);
+        let checked = match stmt {
+            Stmt::Assign { cast, value } => match scope.larvie.cast(cast) {
+                Some(target) if !target.is_stored() => Err(TypeError::Mismatch(format!("cast '{}' is computed and cannot be assigned", cast))),
+                Some(target) => value.expect(&scope.ir.underlying(&target.flora), scope),
+                None => Err(TypeError::Unknown(format!("larvie '{}' has no cast '{}'", scope.larvie.primor, cast))),
+            },
+            Stmt::If { cond, then, otherwise } => {
+                validate_body(then, instinct, scope, &path.key("then"), diagnostics);
+                validate_body(otherwise, instinct, scope, &path.key("otherwise"), diagnostics);
+                cond.expect(&Flora::Bool, scope)
+            }
+            Stmt::Return(value) => match (value, &instinct.returns) {
+                (Some(value), Some(returns)) => value.expect(&scope.ir.underlying(returns), scope),
+                (None, None) => Ok(()),
+                (Some(_), None) => Err(TypeError::Mismatch(format!("instinct '{}' returns nothing", instinct.echo))),
+                (None, Some(returns)) => Err(TypeError::Mismatch(format!("instinct '{}' must return {:?}", instinct.echo, returns))),
+            },
+            Stmt::Raise(fault) => {
+                if !instinct.faults.contains(fault) {
+                    diagnostics.push(Diagnostic::error(
+                        Code::UnknownFault,
+                        path.clone(),
+                        format!("instinct '{}' raises undeclared fault '{}'", instinct.echo, fault),
+                    ).with_span(instinct.span.as_ref()));
+                }
+                Ok(())
+            }
+            // A bare call may discard its result, or have none.
+            Stmt::Expr(Expr::Call { instinct, args }) => body::call(instinct, args, scope).map(|_| ()),
+            Stmt::Expr(expr) => expr.flora(scope).map(|_| ()),
+        };
+
+        if let Err(error) = checked {
+            let code = match error {
+                TypeError::Unknown(_) => Code::UnknownSymbol,
+                TypeError::Mismatch(_) => Code::TypeMismatch,
+            };
+            diagnostics.push(Diagnostic::error(code, path, error.to_string()).with_span(instinct.span.as_ref()));
+        }
+    }
+}
+
+/// Checks the cast list of a constraint or index: non-empty, existing, distinct and scalar.
+fn validate_key_casts(larvie: &Larvie, casts: &[String], path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    if casts.is_empty() {
+        diagnostics.push(Diagnostic::error(
+            Code::InvalidConstraint,
+            path.clone(),
+            format!("constraint on larvie '{}' lists no casts", larvie.primor),
+        ).with_span(larvie.span.as_ref()));
+    }
+
+    for (i, primor) in casts.iter().enumerate() {
+        match larvie.cast(primor) {
+            None => diagnostics.push(Diagnostic::error(
+                Code::UnknownCast,
+                path.clone(),
+                format!("larvie '{}' has no cast '{}'", larvie.primor, primor),
+            ).with_span(larvie.span.as_ref())),
+            Some(_) if casts[..i].contains(primor) => diagnostics.push(Diagnostic::error(
+                Code::InvalidConstraint,
+                path.clone(),
+                format!("cast '{}' appears more than once in a constraint of larvie '{}'", primor, larvie.primor),
+            ).with_span(larvie.span.as_ref())),
+            Some(cast) if !cast.is_stored() => diagnostics.push(Diagnostic::error(
+                Code::InvalidConstraint,
+                path.clone(),
+                format!("computed cast '{}' cannot be part of a key or index", primor),
+            ).with_span(cast.span.as_ref())),
+            Some(cast) if matches!(cast.flora, Flora::List(_) | Flora::Map(_, _)) => diagnostics.push(Diagnostic::error(
+                Code::InvalidConstraint,
+                path.clone(),
+                format!("cast '{}' of {:?} cannot be part of a key or index", primor, cast.flora),
+            ).with_span(cast.span.as_ref())),
+            Some(_) => {}
+        }
+    }
+}
+
+fn validate_casts(cast: &Casts, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    let owner = format!("cast '{}'", cast.primor);
+    validate_flora(&cast.flora, &owner, cast.span.as_ref(), cx, &path.key("flora"), diagnostics);
+
+    let resolved = Casts { flora: cx.ir.underlying(&cast.flora), ..cast.clone() };
+    for (i, seal) in cast.seals.iter().enumerate() {
+        if cast.seals[..i].contains(seal) {
+            diagnostics.push(Diagnostic::warning(
+                Code::DuplicateSeal,
+                path.at("seals", i),
+                format!("seal {:?} is repeated on cast '{}'", seal, cast.primor),
+            ).with_span(cast.span.as_ref()));
+        } else if matches!(seal, Seal::Default(_) | Seal::Range { .. } | Seal::Length { .. })
+            && cast.seals[..i].iter().any(|other| mem::discriminant(other) == mem::discriminant(seal))
+        {
+            diagnostics.push(Diagnostic::error(
+                Code::ConflictingSeals,
+                path.at("seals", i),
+                format!("cast '{}' has conflicting {:?} seals", cast.primor, seal),
+            ).with_span(cast.span.as_ref()));
+        }
+        validate_seal(seal, &resolved, &owner, cx, &path.at("seals", i), diagnostics);
+        if let Seal::Reference { larvie, cast: target, on_delete, on_update } = seal {
+            validate_reference(cast, larvie, target, [on_delete, on_update], cx, &path.at("seals", i), diagnostics);
+        }
+    }
+
+    // A default must also respect the bounds the cast inherits from its alias.
+    let inherited = match &cast.flora {
+        Flora::Bug(name) => cx.ir.alias_seals(name),
+        Flora::Optional(inner) => match inner.as_ref() {
+            Flora::Bug(name) => cx.ir.alias_seals(name),
+            _ => vec![],
+        },
+        _ => vec![],
+    };
+    for (i, seal) in cast.seals.iter().enumerate() {
+        let Seal::Default(literal) = seal else { continue };
+        for bound in inherited.iter().copied().chain(&cast.seals) {
+            if !within(literal, bound) {
+                diagnostics.push(Diagnostic::error(
+                    Code::SealMismatch,
+                    path.at("seals", i),
+                    format!("default {:?} of {} is outside its {:?} seal", literal, owner, bound),
+                ).with_span(cast.span.as_ref()));
+            }
+        }
+    }
+}
+
+/// Whether `literal` satisfies a `Range` or `Length` bound; other seals and literals that do
+/// not compare against the bound pass, as `validate_seal` reports those.
+fn within(literal: &Literal, bound: &Seal) -> bool {
+    match (bound, literal) {
+        (Seal::Range { min, max }, literal) => {
+            min.as_ref().is_none_or(|min| literal.compare(min) != Some(Ordering::Less))
+                && max.as_ref().is_none_or(|max| literal.compare(max) != Some(Ordering::Greater))
+        }
+        (Seal::Length { min, max }, Literal::Str(text)) => {
+            let length = text.chars().count();
+            min.is_none_or(|min| length >= min as usize) && max.is_none_or(|max| length <= max as usize)
+        }
+        _ => true,
+    }
+}
+
+fn validate_reference(
+    cast: &Casts,
+    larvie: &str,
+    target: &str,
+    actions: [&ReferentialAction; 2],
+    cx: &Context,
+    path: &Path,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let invalid = |message: String| Diagnostic::error(Code::InvalidReference, path.clone(), message).with_span(cast.span.as_ref());
+
+    let Some(referenced) = cx.larvie(larvie) else {
+        if let Some(alveolus) = cx.alveolus(larvie) {
+            diagnostics.push(invalid(format!("cast '{}' references {} '{}'; only larvies have rows", cast.primor, alveolus.kind(), larvie)));
+            return;
+        }
+        diagnostics.push(Diagnostic::error(
+            Code::UnknownReference,
+            path.clone(),
+            format!("cast '{}' references unknown larvie '{}'", cast.primor, larvie),
+        ).with_span(cast.span.as_ref()));
+        return;
+    };
+    let Some(target_cast) = referenced.cast(target) else {
+        diagnostics.push(Diagnostic::error(
+            Code::UnknownReference,
+            path.clone(),
+            format!("cast '{}' references unknown cast '{}.{}'", cast.primor, larvie, target),
+        ).with_span(cast.span.as_ref()));
+        return;
+    };
+
+    if !referenced.is_unique_cast(target) {
+        diagnostics.push(invalid(format!("referenced cast '{}.{}' is not a key or unique cast", larvie, target)));
+    }
+
+    let own = match cx.ir.underlying(&cast.flora) {
+        Flora::Optional(inner) => *inner,
+        flora => flora,
+    };
+    match own {
+        Flora::Bug(name) if name == larvie => {}
+        Flora::Bug(name) => {
+            diagnostics.push(invalid(format!("cast '{}' is a {} but references larvie '{}'", cast.primor, name, larvie)));
+        }
+        flora if flora != cx.ir.underlying(&target_cast.flora) => {
+            diagnostics.push(invalid(format!(
+                "cast '{}' of {:?} cannot reference '{}.{}' of {:?}",
+                cast.primor, cast.flora, larvie, target, target_cast.flora
+            )));
+        }
+        _ => {}
+    }
+
+    for action in actions {
+        match action {
+            ReferentialAction::SetNull if cast.seals.contains(&Seal::Vital) => {
+                diagnostics.push(invalid(format!("Vital cast '{}' cannot use SetNull", cast.primor)));
+            }
+            ReferentialAction::SetDefault if !cast.seals.iter().any(|seal| matches!(seal, Seal::Default(_))) => {
+                diagnostics.push(invalid(format!("cast '{}' uses SetDefault without a Default seal", cast.primor)));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Checks `seal` against `cast`, whose flora the caller has already resolved through aliases.
+fn validate_seal(seal: &Seal, cast: &Casts, owner: &str, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    let base = match &cast.flora {
+        Flora::Optional(inner) => inner.as_ref(),
+        flora => flora,
+    };
+    let mismatch = |message: String| Diagnostic::error(Code::SealMismatch, path.clone(), message).with_span(cast.span.as_ref());
+    let invalid = |message: String| Diagnostic::error(Code::InvalidSeal, path.clone(), message).with_span(cast.span.as_ref());
+
+    match seal {
+        Seal::Vital | Seal::Core | Seal::Root | Seal::Reference { .. } => {}
+        Seal::Default(Literal::Null) if cast.seals.contains(&Seal::Vital) => {
+            diagnostics.push(mismatch(format!("Vital {} cannot default to Null", owner)));
+        }
+        Seal::Default(literal) => {
+            // An enumeration-typed cast defaults to one of its members, by name.
+            let fits = match (base, literal) {
+                (Flora::Bug(name), Literal::Str(member)) => cx.enumeration(name).is_some_and(|e| e.member(member).is_some()),
+                _ => literal.fits(&cast.flora),
+            };
+            if !fits {
+                diagnostics.push(mismatch(format!("default {:?} does not fit {} of {:?}", literal, owner, cast.flora)));
+            }
+        }
+        Seal::Range { min, max } => {
+            if !(base.is_numeric() || matches!(base, Flora::Date | Flora::DateTime | Flora::Duration)) {
+                diagnostics.push(mismatch(format!("Range seal does not apply to {} of {:?}", owner, cast.flora)));
+                return;
+            }
+            for bound in [min, max].into_iter().flatten() {
+                if !bound.fits(base) {
+                    diagnostics.push(mismatch(format!("bound {:?} does not fit {} of {:?}", bound, owner, cast.flora)));
+                }
+            }
+            match (min, max) {
+                (None, None) => diagnostics.push(invalid(format!("Range seal on {} has no bounds", owner))),
+                (Some(min), Some(max)) if min.compare(max) == Some(Ordering::Greater) => {
+                    diagnostics.push(invalid(format!("Range seal on {} has min {:?} above max {:?}", owner, min, max)));
+                }
+                _ => {}
+            }
+        }
+        Seal::Length { min, max } => {
+            if !matches!(base, Flora::Str | Flora::Bytes | Flora::List(_) | Flora::Map(_, _)) {
+                diagnostics.push(mismatch(format!("Length seal does not apply to {} of {:?}", owner, cast.flora)));
+            }
+            match (min, max) {
+                (None, None) => diagnostics.push(invalid(format!("Length seal on {} has no bounds", owner))),
+                (Some(min), Some(max)) if min > max => {
+                    diagnostics.push(invalid(format!("Length seal on {} has min {} above max {}", owner, min, max)));
+                }
+                _ => {}
+            }
+        }
+        Seal::Pattern(pattern) => {
+            if *base != Flora::Str {
+                diagnostics.push(mismatch(format!("Pattern seal does not apply to {} of {:?}", owner, cast.flora)));
+            }
+            if pattern.is_empty() {
+                diagnostics.push(invalid(format!("Pattern seal on {} is empty", owner)));
+            }
+        }
+        Seal::Check(expression) => {
+            if expression.trim().is_empty() {
+                diagnostics.push(invalid(format!("Check seal on {} is empty", owner)));
+            }
+        }
+    }
+}
+
+/// Checks a flora wherever it appears; `owner` names the node using it, e.g. `cast 'nest'`.
+fn validate_flora(flora: &Flora, owner: &str, span: Option<&Span>, cx: &Context, path: &Path, diagnostics: &mut Vec<Diagnostic>) {
+    match flora {
+        Flora::Bug(name) if cx.alveolus(name).is_none() => {
+            diagnostics.push(Diagnostic::error(
+                Code::UnknownBug,
+                path.clone(),
+                format!("{} refers to unknown alveolus '{}'", owner, name),
+            ).with_span(span));
+        }
+        Flora::Bug(name) if cx.larvie(name).is_some_and(|larvie| !larvie.generics.is_empty()) => {
+            diagnostics.push(Diagnostic::error(
+                Code::InvalidGeneric,
+                path.clone(),
+                format!("{} uses generic larvie '{}' without type arguments", owner, name),
+            ).with_span(span));
+        }
+        Flora::Param(name) if !cx.generics.contains(name) => {
+            diagnostics.push(Diagnostic::error(
+                Code::UnknownTypeParam,
+                path.clone(),
+                format!("{} uses undeclared type parameter '{}'", owner, name),
+            ).with_span(span));
+        }
+        Flora::Apply { bug, args } => {
+            match cx.alveolus(bug) {
+                None => diagnostics.push(Diagnostic::error(
+                    Code::UnknownBug,
+                    path.clone(),
+                    format!("{} refers to unknown alveolus '{}'", owner, bug),
+                ).with_span(span)),
+                Some(Alveolus::Larvie(generic)) if generic.generics.len() == args.len() => {}
+                Some(Alveolus::Larvie(generic)) if !generic.generics.is_empty() => diagnostics.push(Diagnostic::error(
+                    Code::InvalidGeneric,
+                    path.clone(),
+                    format!("{} applies {} type arguments to '{}', which takes {}", owner, args.len(), bug, generic.generics.len()),
+                ).with_span(span)),
+                Some(other) => diagnostics.push(Diagnostic::error(
+                    Code::InvalidGeneric,
+                    path.clone(),
+                    format!("{} applies type arguments to {} '{}', which is not generic", owner, other.kind(), bug),
+                ).with_span(span)),
+            }
+            for arg in args {
+                validate_flora(arg, owner, span, cx, path, diagnostics);
+            }
+        }
+        Flora::Bug(name) if matches!(cx.alveolus(name), Some(Alveolus::Interface(_))) => {
+            diagnostics.push(Diagnostic::error(
+                Code::InvalidInterface,
+                path.clone(),
+                format!("{} uses interface '{}' as a flora; interfaces carry no data", owner, name),
+            ).with_span(span));
+        }
+        Flora::Bug(name) if matches!(cx.alveolus(name), Some(Alveolus::Mixin(_))) => {
+            diagnostics.push(Diagnostic::error(
+                Code::InvalidMixin,
+                path.clone(),
+                format!("{} uses mixin '{}' as a flora; mixins are only included into larvies", owner, name),
+            ).with_span(span));
+        }
+        Flora::Bug(name) if matches!(cx.alveolus(name), Some(Alveolus::Lifecycle(_))) => {
+            diagnostics.push(Diagnostic::error(
+                Code::InvalidLifecycle,
+                path.clone(),
+                format!("{} uses lifecycle '{}' as a flora; lifecycles only describe the states of a larvie", owner, name),
+            ).with_span(span));
+        }
+        Flora::Bug(name) if matches!(cx.alveolus(name), Some(Alveolus::View(_))) => {
+            diagnostics.push(Diagnostic::error(
+                Code::InvalidView,
+                path.clone(),
+                format!("{} uses view '{}' as a flora; views are read-only projections of a larvie", owner, name),
+            ).with_span(span));
+        }
+        Flora::Bug(name) if matches!(cx.alveolus(name), Some(Alveolus::Module(_))) => {
+            diagnostics.push(Diagnostic::error(
+                Code::InvalidModule,
+                path.clone(),
+                format!("{} uses module '{}' as a flora; modules only group alveolus", owner, name),
+            ).with_span(span));
+        }
+        Flora::Decimal { precision, scale } if *precision == 0 || scale > precision => {
+            diagnostics.push(Diagnostic::error(
+                Code::InvalidDecimal,
+                path.clone(),
+                format!(
+                    "{} has decimal precision {} and scale {}; precision must be positive and at least the scale",
+                    owner, precision, scale
+                ),
+            ).with_span(span));
+        }
+        Flora::List(inner) | Flora::Optional(inner) => validate_flora(inner, owner, span, cx, path, diagnostics),
+        Flora::Map(key, value) => {
+            let enumeration = matches!(key.as_ref(), Flora::Bug(name) if cx.enumeration(name).is_some());
+            // A type parameter is checked on each instance `monomorphize` emits.
+            let param = matches!(key.as_ref(), Flora::Param(_));
+            if !cx.ir.underlying(key).is_scalar() && !enumeration && !param {
+                diagnostics.push(Diagnostic::error(
+                    Code::InvalidMapKey,
+                    path.clone(),
+                    format!("{} uses {:?} as a map key; keys must be scalar or an enumeration", owner, key),
+                ).with_span(span));
+            }
+            validate_flora(key, owner, span, cx, path, diagnostics);
+            validate_flora(value, owner, span, cx, path, diagnostics);
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::attribute::Attributes;
+    use crate::ir::relation::{Cardinality, Relation};
+    use crate::ir::{
+        body::{BinOp, UnOp},
+        constraint::{Index, IndexPart, Order},
+        diagnostic::Severity,
+        alias::AliasKind,
+        enumeration::Member,
+        fixture::{cast, larvie},
+        mixin::Mixin,
+        module::Module,
+        event::Event,
+        view::Selection,
+        lifecycle::{State, Transition},
+        union::Variant,
+        instincts::Param,
+    };
+
+    fn codes(ir: &IR) -> Vec<Code> {
+        ir.validate().into_iter().map(|d| d.code).collect()
+    }
+
+    #[test]
+    fn test_valid_ir_has_no_diagnostics() {
+        let ir = IR {
+            alveolus: vec![
+                larvie("Nest", vec![cast("id", Flora::Int, vec![Seal::Core])]),
+                larvie("Bird", vec![
+                    cast("id", Flora::Int, vec![Seal::Core]),
+                    cast("nest", Flora::Bug("Nest".to_string()), vec![Seal::Vital]),
+                ]),
+            ],
+        };
+
+        assert!(ir.validate().is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_larvie() {
+        let ir = IR {
+            alveolus: vec![larvie("Bird", vec![]), larvie("Bird", vec![]), larvie("Bird", vec![])],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].code, Code::DuplicateLarvie);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[1]");
+        assert!(diagnostics[1].message.contains("alveolus[0]"));
+    }
+
+    #[test]
+    fn test_duplicate_cast() {
+        let ir = IR {
+            alveolus: vec![larvie("Bird", vec![
+                cast("energy", Flora::Int, vec![]),
+                cast("energy", Flora::Str, vec![]),
+            ])],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, Code::DuplicateCast);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[0].casts[1]");
+    }
+
+    #[test]
+    fn test_unknown_bug() {
+        let ir = IR {
+            alveolus: vec![larvie("Bird", vec![cast("nest", Flora::Bug("Nest".to_string()), vec![])])],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::UnknownBug]);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[0].casts[0].flora");
+    }
+
+    #[test]
+    fn test_multiple_core() {
+        let ir = IR {
+            alveolus: vec![larvie("Bird", vec![
+                cast("id", Flora::Int, vec![Seal::Core]),
+                cast("code", Flora::Str, vec![Seal::Core]),
+            ])],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::MultipleCore]);
+        assert!(diagnostics[0].message.contains("id, code"));
+    }
+
+    #[test]
+    fn test_duplicate_instinct() {
+        let ir = IR {
+            alveolus: vec![Alveolus::Larvie(Larvie {
+                primor: "Bird".to_string(),
+                casts: vec![],
+                instincts: vec![
+                    Instinct { echo: "fly".to_string(), ..Default::default() },
+                    Instinct { echo: "fly".to_string(), ..Default::default() },
+                ],
+                ..Default::default()
+            })],
+        };
+
+        assert_eq!(codes(&ir), vec![Code::DuplicateInstinct]);
+    }
+
+    #[test]
+    fn test_duplicate_seal_is_a_warning() {
+        let ir = IR {
+            alveolus: vec![larvie("Bird", vec![cast("id", Flora::Int, vec![Seal::Vital, Seal::Vital])])],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::DuplicateSeal]);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[0].casts[0].seals[1]");
+    }
+
+    #[test]
+    fn test_unknown_bug_inside_collection() {
+        let ir = IR {
+            alveolus: vec![larvie("Hive", vec![
+                cast("bees", Flora::List(Box::new(Flora::Bug("Bee".to_string()))), vec![]),
+                cast("queen", Flora::Optional(Box::new(Flora::Bug("Hive".to_string()))), vec![]),
+            ])],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::UnknownBug]);
+        assert!(diagnostics[0].message.contains("'Bee'"));
+    }
+
+    #[test]
+    fn test_invalid_map_key() {
+        let ir = IR {
+            alveolus: vec![larvie("Hive", vec![
+                cast("by_name", Flora::Map(Box::new(Flora::Str), Box::new(Flora::Int)), vec![]),
+                cast("by_list", Flora::Map(Box::new(Flora::List(Box::new(Flora::Int))), Box::new(Flora::Int)), vec![]),
+            ])],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::InvalidMapKey]);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[0].casts[1].flora");
+    }
+
+    #[test]
+    fn test_invalid_decimal() {
+        let ir = IR {
+            alveolus: vec![larvie("Ledger", vec![
+                cast("amount", Flora::Decimal { precision: 12, scale: 2 }, vec![]),
+                cast("rate", Flora::Decimal { precision: 2, scale: 4 }, vec![]),
+                cast("rates", Flora::List(Box::new(Flora::Decimal { precision: 0, scale: 0 })), vec![]),
+            ])],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::InvalidDecimal, Code::InvalidDecimal]);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[0].casts[1].flora");
+        assert_eq!(diagnostics[1].path.to_string(), "alveolus[0].casts[2].flora");
+    }
+
+    #[test]
+    fn test_parameterized_seals_accepted() {
+        let ir = IR {
+            alveolus: vec![larvie("Bird", vec![
+                cast("energy", Flora::Int, vec![
+                    Seal::Default(Literal::Int(100)),
+                    Seal::Range { min: Some(Literal::Int(0)), max: Some(Literal::Int(1000)) },
+                ]),
+                cast("name", Flora::Str, vec![
+                    Seal::Length { min: Some(1), max: Some(64) },
+                    Seal::Pattern("^[A-Z]".to_string()),
+                ]),
+                cast("nickname", Flora::Optional(Box::new(Flora::Str)), vec![Seal::Default(Literal::Null)]),
+                cast("wings", Flora::U8, vec![Seal::Check("wings % 2 = 0".to_string())]),
+            ])],
+        };
+
+        assert!(ir.validate().is_empty());
+    }
+
+    #[test]
+    fn test_seal_mismatch() {
+        let ir = IR {
+            alveolus: vec![larvie("Bird", vec![
+                cast("energy", Flora::Int, vec![Seal::Default(Literal::Str("full".to_string()))]),
+                cast("name", Flora::Str, vec![Seal::Range { min: Some(Literal::Int(0)), max: None }]),
+                cast("alive", Flora::Bool, vec![Seal::Length { min: None, max: Some(1) }]),
+                cast("age", Flora::Int, vec![Seal::Pattern("[0-9]+".to_string())]),
+                cast("tag", Flora::Str, vec![Seal::Vital, Seal::Default(Literal::Null)]),
+            ])],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::SealMismatch; 5]);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[0].casts[0].seals[0]");
+        assert_eq!(diagnostics[4].path.to_string(), "alveolus[0].casts[4].seals[1]");
+    }
+
+    #[test]
+    fn test_invalid_seal_bounds() {
+        let ir = IR {
+            alveolus: vec![larvie("Bird", vec![
+                cast("energy", Flora::Int, vec![Seal::Range { min: Some(Literal::Int(10)), max: Some(Literal::Int(1)) }]),
+                cast("name", Flora::Str, vec![Seal::Length { min: Some(8), max: Some(4) }]),
+                cast("code", Flora::Str, vec![Seal::Length { min: None, max: None }, Seal::Pattern(String::new())]),
+                cast("wings", Flora::Int, vec![Seal::Check("  ".to_string())]),
+            ])],
+        };
+
+        assert_eq!(codes(&ir), vec![Code::InvalidSeal; 5]);
+    }
+
+    #[test]
+    fn test_conflicting_seals() {
+        let ir = IR {
+            alveolus: vec![larvie("Bird", vec![
+                cast("energy", Flora::Int, vec![Seal::Default(Literal::Int(1)), Seal::Default(Literal::Int(2))]),
+                cast("checks", Flora::Int, vec![Seal::Check("a".to_string()), Seal::Check("b".to_string())]),
+            ])],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::ConflictingSeals]);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[0].casts[0].seals[1]");
+    }
+
+    fn reference(larvie: &str, cast: &str, on_delete: ReferentialAction) -> Seal {
+        Seal::Reference {
+            larvie: larvie.to_string(),
+            cast: cast.to_string(),
+            on_delete,
+            on_update: ReferentialAction::Cascade,
+        }
+    }
+
+    #[test]
+    fn test_valid_references() {
+        let ir = IR {
+            alveolus: vec![
+                larvie("Nest", vec![cast("id", Flora::Uuid, vec![Seal::Core])]),
+                larvie("Bird", vec![
+                    cast("nest", Flora::Bug("Nest".to_string()), vec![Seal::Vital, reference("Nest", "id", ReferentialAction::Cascade)]),
+                    cast("nest_id", Flora::Optional(Box::new(Flora::Uuid)), vec![reference("Nest", "id", ReferentialAction::SetNull)]),
+                ]),
+            ],
+        };
+
+        assert!(ir.validate().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_reference() {
+        let ir = IR {
+            alveolus: vec![
+                larvie("Nest", vec![cast("id", Flora::Int, vec![Seal::Core])]),
+                larvie("Bird", vec![
+                    cast("tree", Flora::Int, vec![reference("Tree", "id", ReferentialAction::NoAction)]),
+                    cast("nest", Flora::Int, vec![reference("Nest", "code", ReferentialAction::NoAction)]),
+                ]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::UnknownReference, Code::UnknownReference]);
+        assert_eq!(diagnostics[1].path.to_string(), "alveolus[1].casts[1].seals[0]");
+        assert!(diagnostics[1].message.contains("'Nest.code'"));
+    }
+
+    #[test]
+    fn test_invalid_reference() {
+        let ir = IR {
+            alveolus: vec![
+                larvie("Nest", vec![
+                    cast("id", Flora::Int, vec![Seal::Core]),
+                    cast("label", Flora::Str, vec![]),
+                ]),
+                larvie("Tree", vec![cast("id", Flora::Int, vec![Seal::Core])]),
+                larvie("Bird", vec![
+                    cast("label", Flora::Str, vec![reference("Nest", "label", ReferentialAction::NoAction)]),
+                    cast("nest", Flora::Str, vec![reference("Nest", "id", ReferentialAction::NoAction)]),
+                    cast("tree", Flora::Bug("Tree".to_string()), vec![reference("Nest", "id", ReferentialAction::NoAction)]),
+                    cast("home", Flora::Int, vec![Seal::Vital, reference("Nest", "id", ReferentialAction::SetNull)]),
+                    cast("perch", Flora::Int, vec![reference("Tree", "id", ReferentialAction::SetDefault)]),
+                ]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::InvalidReference; 5]);
+        assert!(diagnostics[0].message.contains("not a key or unique cast"));
+        assert!(diagnostics[3].message.contains("SetNull"));
+        assert!(diagnostics[4].message.contains("SetDefault"));
+    }
+
+    fn with_constraints(casts: Vec<Casts>, constraints: Vec<Constraint>, indexes: Vec<Index>) -> IR {
+        IR {
+            alveolus: vec![Alveolus::Larvie(Larvie {
+                primor: "Cell".to_string(),
+                casts,
+                constraints,
+                indexes,
+                ..Default::default()
+            })],
+        }
+    }
+
+    fn names(casts: &[&str]) -> Vec<String> {
+        casts.iter().map(|cast| cast.to_string()).collect()
+    }
+
+    #[test]
+    fn test_valid_constraints_and_indexes() {
+        let ir = with_constraints(
+            vec![
+                cast("hive", Flora::Int, vec![]),
+                cast("slot", Flora::Int, vec![]),
+                cast("label", Flora::Str, vec![]),
+            ],
+            vec![
+                Constraint::key(names(&["hive", "slot"])),
+                Constraint::Unique {
+                    primor: Some("uq_label".to_string()),
+                    casts: names(&["hive", "label"]),
+                    doc: None,
+                    attributes: Attributes::new(),
+                },
+            ],
+            vec![Index {
+                primor: "by_label".to_string(),
+                parts: vec![IndexPart { cast: "label".to_string(), order: Order::Desc }],
+                ..Default::default()
+            }],
+        );
+
+        assert!(ir.validate().is_empty());
+    }
+
+    #[test]
+    fn test_constraint_errors() {
+        let ir = with_constraints(
+            vec![
+                cast("hive", Flora::Int, vec![]),
+                cast("tags", Flora::List(Box::new(Flora::Str)), vec![]),
+            ],
+            vec![
+                Constraint::unique(vec![]),
+                Constraint::unique(names(&["hive", "wing", "hive"])),
+                Constraint::unique(names(&["tags"])),
+            ],
+            vec![],
+        );
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(
+            codes(&ir),
+            vec![Code::InvalidConstraint, Code::UnknownCast, Code::InvalidConstraint, Code::InvalidConstraint]
+        );
+        assert_eq!(diagnostics[1].path.to_string(), "alveolus[0].constraints[1]");
+    }
+
+    #[test]
+    fn test_key_constraint_conflicts_with_core() {
+        let ir = with_constraints(
+            vec![cast("id", Flora::Int, vec![Seal::Core]), cast("slot", Flora::Int, vec![])],
+            vec![Constraint::key(names(&["id", "slot"]))],
+            vec![],
+        );
+
+        assert_eq!(codes(&ir), vec![Code::MultipleCore]);
+    }
+
+    #[test]
+    fn test_index_errors() {
+        let index = |primor: &str, casts: &[&str]| Index {
+            primor: primor.to_string(),
+            parts: casts.iter().map(|cast| IndexPart { cast: cast.to_string(), order: Order::Asc }).collect(),
+            ..Default::default()
+        };
+        let ir = with_constraints(
+            vec![cast("energy", Flora::Int, vec![])],
+            vec![],
+            vec![index("by_energy", &["energy"]), index("by_energy", &["energy"]), index("by_wing", &["wing"])],
+        );
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::DuplicateIndex, Code::UnknownCast]);
+        assert_eq!(diagnostics[1].path.to_string(), "alveolus[0].indexes[2]");
+    }
+
+    #[test]
+    fn test_reference_to_single_cast_unique_constraint() {
+        let ir = IR {
+            alveolus: vec![
+                Alveolus::Larvie(Larvie {
+                    primor: "Nest".to_string(),
+                    casts: vec![cast("code", Flora::Str, vec![])],
+                    constraints: vec![Constraint::unique(names(&["code"]))],
+                    ..Default::default()
+                }),
+                larvie("Bird", vec![cast("nest", Flora::Str, vec![reference("Nest", "code", ReferentialAction::NoAction)])]),
+            ],
+        };
+
+        assert!(ir.validate().is_empty());
+    }
+
+    #[test]
+    fn test_instinct_signature_checks() {
+        let param = |primor: &str, flora| Param::new(primor, flora);
+        let ir = IR {
+            alveolus: vec![Alveolus::Larvie(Larvie {
+                primor: "Bird".to_string(),
+                instincts: vec![
+                    Instinct {
+                        echo: "fly".to_string(),
+                        params: vec![param("height", Flora::Int), param("height", Flora::Int), param("to", Flora::Bug("Tree".to_string()))],
+                        returns: Some(Flora::List(Box::new(Flora::Bug("Nest".to_string())))),
+                        faults: vec!["Tired".to_string(), "Tired".to_string()],
+                        ..Default::default()
+                    },
+                    Instinct {
+                        echo: "land".to_string(),
+                        params: vec![param("on", Flora::Bug("Bird".to_string()))],
+                        returns: Some(Flora::Bool),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            })],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::DuplicateParam, Code::UnknownBug, Code::UnknownBug, Code::DuplicateFault]);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[0].instincts[0].params[1]");
+        assert_eq!(diagnostics[1].path.to_string(), "alveolus[0].instincts[0].params[2].flora");
+        assert_eq!(diagnostics[2].path.to_string(), "alveolus[0].instincts[0].returns");
+        assert_eq!(diagnostics[3].severity, Severity::Warning);
+    }
+
+    fn bird_with_body(body: Vec<Stmt>) -> IR {
+        IR {
+            alveolus: vec![Alveolus::Larvie(Larvie {
+                primor: "Bird".to_string(),
+                casts: vec![cast("energy", Flora::U16, vec![]), cast("name", Flora::Str, vec![])],
+                instincts: vec![Instinct {
+                    echo: "fly".to_string(),
+                    params: vec![Param::new("height", Flora::U16)],
+                    returns: Some(Flora::Bool),
+                    faults: vec!["Tired".to_string()],
+                    body,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })],
+        }
+    }
+
+    #[test]
+    fn test_valid_instinct_body() {
+        let energy = || Box::new(Expr::Cast("energy".to_string()));
+        let ir = bird_with_body(vec![
+            Stmt::If {
+                cond: Expr::Binary { op: BinOp::Lt, lhs: energy(), rhs: Box::new(Expr::Param("height".to_string())) },
+                then: vec![Stmt::Raise("Tired".to_string())],
+                otherwise: vec![],
+            },
+            Stmt::Assign {
+                cast: "energy".to_string(),
+                value: Expr::Binary { op: BinOp::Sub, lhs: energy(), rhs: Box::new(Expr::Param("height".to_string())) },
+            },
+            Stmt::Return(Some(Expr::Literal(Literal::Bool(true)))),
+        ]);
+
+        assert!(ir.validate().is_empty());
+    }
+
+    #[test]
+    fn test_instinct_body_errors() {
+        let ir = bird_with_body(vec![
+            Stmt::Assign { cast: "name".to_string(), value: Expr::Cast("energy".to_string()) },
+            Stmt::Assign { cast: "wings".to_string(), value: Expr::Literal(Literal::Int(2)) },
+            Stmt::If {
+                cond: Expr::Cast("energy".to_string()),
+                then: vec![Stmt::Raise("Lost".to_string())],
+                otherwise: vec![Stmt::Expr(Expr::Call { instinct: "sing".to_string(), args: vec![] })],
+            },
+            Stmt::Return(None),
+        ]);
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![
+            Code::TypeMismatch,
+            Code::UnknownSymbol,
+            Code::UnknownFault,
+            Code::UnknownSymbol,
+            Code::TypeMismatch,
+            Code::TypeMismatch,
+        ]);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[0].instincts[0].body[0]");
+        assert_eq!(diagnostics[2].path.to_string(), "alveolus[0].instincts[0].body[2].then[0]");
+        assert_eq!(diagnostics[3].path.to_string(), "alveolus[0].instincts[0].body[2].otherwise[0]");
+        assert_eq!(diagnostics[4].path.to_string(), "alveolus[0].instincts[0].body[2]");
+    }
+
+    fn color(members: Vec<Member>) -> Alveolus {
+        Alveolus::Enumeration(Enumeration { primor: "Color".to_string(), members, ..Default::default() })
+    }
+
+    fn member(primor: &str, value: Option<Literal>) -> Member {
+        Member { primor: primor.to_string(), value, ..Default::default() }
+    }
+
+    #[test]
+    fn test_enumeration_referenced_from_floras() {
+        let ir = IR {
+            alveolus: vec![
+                color(vec![member("Red", Some(Literal::Int(1))), member("Green", Some(Literal::Int(2))), member("Blue", None)]),
+                larvie("Bird", vec![
+                    cast("color", Flora::Bug("Color".to_string()), vec![Seal::Default(Literal::Str("Red".to_string()))]),
+                    cast("seen", Flora::List(Box::new(Flora::Bug("Color".to_string()))), vec![]),
+                    cast("sightings", Flora::Map(Box::new(Flora::Bug("Color".to_string())), Box::new(Flora::Int)), vec![]),
+                ]),
+            ],
+        };
+
+        assert!(ir.validate().is_empty());
+    }
+
+    #[test]
+    fn test_enumeration_errors() {
+        let ir = IR {
+            alveolus: vec![
+                color(vec![
+                    member("Red", Some(Literal::Int(1))),
+                    member("Red", None),
+                    member("Green", Some(Literal::Int(1))),
+                    member("Blue", Some(Literal::Str("blue".to_string()))),
+                    member("Black", Some(Literal::Bool(false))),
+                ]),
+                Alveolus::Enumeration(Enumeration { primor: "Empty".to_string(), ..Default::default() }),
+                color(vec![member("Red", None)]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![
+            Code::DuplicateEnumeration,
+            Code::DuplicateMember,
+            Code::DuplicateMember,
+            Code::InvalidMember,
+            Code::InvalidMember,
+            Code::InvalidMember,
+        ]);
+        assert_eq!(diagnostics[0].message, "enumeration 'Color' is already defined at alveolus[0]");
+        assert_eq!(diagnostics[1].path.to_string(), "alveolus[0].members[1]");
+        assert_eq!(diagnostics[2].path.to_string(), "alveolus[0].members[2].value");
+        assert_eq!(diagnostics[5].path.to_string(), "alveolus[1]");
+    }
+
+    #[test]
+    fn test_enumeration_misuse_in_casts() {
+        let ir = IR {
+            alveolus: vec![
+                color(vec![member("Red", None)]),
+                larvie("Bird", vec![
+                    cast("color", Flora::Bug("Color".to_string()), vec![Seal::Default(Literal::Str("Purple".to_string()))]),
+                    cast("tint", Flora::Bug("Color".to_string()), vec![reference("Color", "Red", ReferentialAction::NoAction)]),
+                    cast("shade", Flora::Bug("Shade".to_string()), vec![]),
+                ]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::SealMismatch, Code::InvalidReference, Code::UnknownBug]);
+        assert_eq!(diagnostics[2].message, "cast 'shade' refers to unknown alveolus 'Shade'");
+    }
+
+    fn outcome(variants: Vec<Variant>) -> Alveolus {
+        Alveolus::Union(Union { primor: "Outcome".to_string(), variants, ..Default::default() })
+    }
+
+    fn variant(primor: &str, casts: Vec<Casts>) -> Variant {
+        Variant { primor: primor.to_string(), casts, ..Default::default() }
+    }
+
+    #[test]
+    fn test_union_referenced_from_floras() {
+        let ir = IR {
+            alveolus: vec![
+                outcome(vec![
+                    variant("Landed", vec![cast("on", Flora::Bug("Tree".to_string()), vec![Seal::Vital])]),
+                    variant("Lost", vec![]),
+                ]),
+                larvie("Tree", vec![cast("id", Flora::Int, vec![Seal::Core])]),
+                larvie("Flight", vec![cast("outcome", Flora::Optional(Box::new(Flora::Bug("Outcome".to_string()))), vec![])]),
+            ],
+        };
+
+        assert!(ir.validate().is_empty());
+    }
+
+    #[test]
+    fn test_union_errors() {
+        let ir = IR {
+            alveolus: vec![
+                outcome(vec![
+                    variant("Landed", vec![
+                        cast("on", Flora::Bug("Nest".to_string()), vec![]),
+                        cast("on", Flora::Int, vec![Seal::Core]),
+                    ]),
+                    variant("Landed", vec![]),
+                ]),
+                Alveolus::Union(Union { primor: "Nothing".to_string(), ..Default::default() }),
+                outcome(vec![variant("Lost", vec![])]),
+                larvie("Flight", vec![
+                    cast("outcome", Flora::Bug("Outcome".to_string()), vec![reference("Outcome", "Lost", ReferentialAction::NoAction)]),
+                    cast("by", Flora::Map(Box::new(Flora::Bug("Outcome".to_string())), Box::new(Flora::Int)), vec![]),
+                ]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![
+            Code::DuplicateUnion,
+            Code::UnknownBug,
+            Code::DuplicateCast,
+            Code::InvalidSeal,
+            Code::DuplicateVariant,
+            Code::InvalidVariant,
+            Code::InvalidReference,
+            Code::InvalidMapKey,
+        ]);
+        assert_eq!(diagnostics[2].path.to_string(), "alveolus[0].variants[0].casts[1]");
+        assert_eq!(diagnostics[3].path.to_string(), "alveolus[0].variants[0].casts[1].seals[0]");
+        assert_eq!(diagnostics[6].message, "cast 'outcome' references union 'Outcome'; only larvies have rows");
+    }
+
+    fn archivable() -> Alveolus {
+        Alveolus::Interface(Interface {
+            primor: "Archivable".to_string(),
+            instincts: vec![
+                Instinct { echo: "archive".to_string(), returns: Some(Flora::Bool), ..Default::default() },
+                Instinct { echo: "restore".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        })
+    }
+
+    fn implementing(instincts: Vec<Instinct>, implements: &[&str]) -> Alveolus {
+        Alveolus::Larvie(Larvie {
+            primor: "Bird".to_string(),
+            instincts,
+            implements: names(implements),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_larvie_conforms_to_interface() {
+        let ir = IR {
+            alveolus: vec![
+                archivable(),
+                implementing(vec![
+                    Instinct { echo: "archive".to_string(), returns: Some(Flora::Bool), ..Default::default() },
+                    Instinct { echo: "restore".to_string(), ..Default::default() },
+                    Instinct { echo: "fly".to_string(), ..Default::default() },
+                ], &["Archivable"]),
+            ],
+        };
+
+        assert!(ir.validate().is_empty());
+    }
+
+    #[test]
+    fn test_interface_conformance_errors() {
+        let ir = IR {
+            alveolus: vec![
+                archivable(),
+                larvie("Nest", vec![]),
+                implementing(vec![Instinct { echo: "archive".to_string(), returns: Some(Flora::Int), ..Default::default() }], &[
+                    "Archivable",
+                    "Nest",
+                    "Flyer",
+                ]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::SignatureMismatch, Code::MissingInstinct, Code::UnknownInterface, Code::UnknownInterface]);
+        assert_eq!(diagnostics[1].path.to_string(), "alveolus[2].implements[0]");
+        assert_eq!(diagnostics[1].message, "larvie 'Bird' does not provide instinct 'restore' required by interface 'Archivable'");
+        assert_eq!(diagnostics[2].message, "larvie 'Bird' implements larvie 'Nest', which is not an interface");
+    }
+
+    #[test]
+    fn test_inherited_instincts_satisfy_interfaces() {
+        let archive = Instinct { echo: "archive".to_string(), returns: Some(Flora::Bool), ..Default::default() };
+        let restore = Instinct { echo: "restore".to_string(), ..Default::default() };
+        let ir = IR {
+            alveolus: vec![
+                archivable(),
+                Alveolus::Larvie(Larvie { primor: "Animal".to_string(), instincts: vec![archive, restore], ..Default::default() }),
+                Alveolus::Larvie(Larvie {
+                    primor: "Bird".to_string(),
+                    extends: Some("Animal".to_string()),
+                    implements: names(&["Archivable"]),
+                    ..Default::default()
+                }),
+            ],
+        };
+
+        assert!(ir.validate().is_empty());
+        assert!(ir.flatten().unwrap().validate().is_empty());
+    }
+
+    #[test]
+    fn test_interface_declaration_errors() {
+        let ir = IR {
+            alveolus: vec![
+                Alveolus::Interface(Interface {
+                    primor: "Archivable".to_string(),
+                    instincts: vec![
+                        Instinct { echo: "archive".to_string(), body: vec![Stmt::Return(None)], ..Default::default() },
+                        Instinct { echo: "archive".to_string(), returns: Some(Flora::Bug("Receipt".to_string())), ..Default::default() },
+                    ],
+                    ..Default::default()
+                }),
+                archivable(),
+                larvie("Shelf", vec![cast("item", Flora::Bug("Archivable".to_string()), vec![])]),
+            ],
+        };
+
+        assert_eq!(codes(&ir), vec![
+            Code::DuplicateInterface,
+            Code::InvalidInterface,
+            Code::DuplicateInstinct,
+            Code::UnknownBug,
+            Code::InvalidInterface,
+        ]);
+    }
+
+    #[test]
+    fn test_extension_errors() {
+        let extending = |primor: &str, parent: Option<&str>, mixins: &[&str]| {
+            Alveolus::Larvie(Larvie {
+                primor: primor.to_string(),
+                extends: parent.map(str::to_string),
+                mixins: names(mixins),
+                ..Default::default()
+            })
+        };
+        let ir = IR {
+            alveolus: vec![
+                Alveolus::Mixin(Mixin {
+                    primor: "Audited".to_string(),
+                    casts: vec![cast("at", Flora::DateTime, vec![]), cast("at", Flora::DateTime, vec![])],
+                    ..Default::default()
+                }),
+                extending("Egg", Some("Bird"), &["Audited"]),
+                extending("Bird", Some("Egg"), &[]),
+                extending("Nest", Some("Audited"), &["Egg", "Tracked"]),
+                larvie("Tree", vec![cast("audit", Flora::Bug("Audited".to_string()), vec![])]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![
+            Code::DuplicateCast,
+            Code::InheritanceCycle,
+            Code::InheritanceCycle,
+            Code::UnknownParent,
+            Code::UnknownMixin,
+            Code::UnknownMixin,
+            Code::InvalidMixin,
+        ]);
+        assert_eq!(diagnostics[1].message, "larvie 'Egg' extends itself through 'Bird'");
+        assert_eq!(diagnostics[4].path.to_string(), "alveolus[3].mixins[0]");
+    }
+
+    #[test]
+    fn test_generic_checks() {
+        let apply = |bug: &str, args: Vec<Flora>| Flora::Apply { bug: bug.to_string(), args };
+        let t = || Flora::Param("T".to_string());
+        let ir = IR {
+            alveolus: vec![
+                Alveolus::Larvie(Larvie {
+                    primor: "Page".to_string(),
+                    generics: names(&["T", "T"]),
+                    casts: vec![cast("items", Flora::List(Box::new(t())), vec![]), cast("key", Flora::Param("K".to_string()), vec![])],
+                    ..Default::default()
+                }),
+                larvie("Feed", vec![
+                    cast("ok", apply("Page", vec![Flora::Int, Flora::Int]), vec![]),
+                    cast("arity", apply("Page", vec![Flora::Int]), vec![]),
+                    cast("bare", Flora::Bug("Page".to_string()), vec![]),
+                    cast("plain", apply("Feed", vec![Flora::Int]), vec![]),
+                    cast("unknown", apply("Book", vec![Flora::Bug("Nest".to_string())]), vec![]),
+                    cast("loose", t(), vec![]),
+                ]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![
+            Code::InvalidGeneric,
+            Code::UnknownTypeParam,
+            Code::InvalidGeneric,
+            Code::InvalidGeneric,
+            Code::InvalidGeneric,
+            Code::UnknownBug,
+            Code::UnknownBug,
+            Code::UnknownTypeParam,
+        ]);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[0].generics[1]");
+        assert_eq!(diagnostics[2].message, "cast 'arity' applies 1 type arguments to 'Page', which takes 2");
+        assert_eq!(diagnostics[4].message, "cast 'plain' applies type arguments to larvie 'Feed', which is not generic");
+    }
+
+    #[test]
+    fn test_generic_map_keys_are_checked_per_instance() {
+        let ir = crate::ir::parse("bug Index<K> gene entries Map<K, Int> end bug Feed gene ok Index<Str> gene bad Index<List<Int>> end").unwrap();
+
+        assert!(ir.validate().is_empty());
+
+        let mono = ir.monomorphize().unwrap();
+        let diagnostics = mono.validate();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, Code::InvalidMapKey);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[2].casts[0].flora");
+        assert_eq!(diagnostics[0].message, "cast 'entries' uses List(Int) as a map key; keys must be scalar or an enumeration");
+    }
+
+    fn alias(primor: &str, flora: Flora, seals: Vec<Seal>) -> Alveolus {
+        Alveolus::Alias(Alias { seals, kind: AliasKind::Nominal, ..Alias::new(primor, flora) })
+    }
+
+    #[test]
+    fn test_aliases_resolve_for_seals_and_references() {
+        let energy = || Flora::Bug("Energy".to_string());
+        let ir = IR {
+            alveolus: vec![
+                alias("Energy", Flora::U16, vec![Seal::Range { min: Some(Literal::Int(0)), max: Some(Literal::Int(1000)) }]),
+                alias("Email", Flora::Str, vec![Seal::Pattern("^.+@.+$".to_string())]),
+                larvie("Hive", vec![cast("level", energy(), vec![Seal::Core])]),
+                larvie("Bird", vec![
+                    cast("energy", energy(), vec![Seal::Default(Literal::Int(100)), Seal::Range { min: None, max: Some(Literal::Int(500)) }]),
+                    cast("contact", Flora::Optional(Box::new(Flora::Bug("Email".to_string()))), vec![Seal::Length { min: Some(3), max: None }]),
+                    cast("hive", Flora::U16, vec![reference("Hive", "level", ReferentialAction::NoAction)]),
+                    cast("by_mail", Flora::Map(Box::new(Flora::Bug("Email".to_string())), Box::new(Flora::Int)), vec![]),
+                ]),
+            ],
+        };
+
+        assert!(ir.validate().is_empty());
+    }
+
+    #[test]
+    fn test_defaults_respect_alias_bounds() {
+        let ir = IR {
+            alveolus: vec![
+                alias("Energy", Flora::Int, vec![Seal::Range { min: Some(Literal::Int(0)), max: Some(Literal::Int(1000)) }]),
+                alias("Boost", Flora::Bug("Energy".to_string()), vec![]),
+                alias("Code", Flora::Str, vec![Seal::Length { min: Some(2), max: Some(4) }]),
+                larvie("Bird", vec![
+                    cast("energy", Flora::Bug("Energy".to_string()), vec![Seal::Default(Literal::Int(5000))]),
+                    cast("boost", Flora::Optional(Box::new(Flora::Bug("Boost".to_string()))), vec![Seal::Default(Literal::Int(-1))]),
+                    cast("code", Flora::Bug("Code".to_string()), vec![Seal::Default(Literal::Str("toolong".to_string()))]),
+                    cast("level", Flora::Int, vec![Seal::Default(Literal::Int(20)), Seal::Range { min: None, max: Some(Literal::Int(10)) }]),
+                    cast("fine", Flora::Bug("Energy".to_string()), vec![Seal::Default(Literal::Int(500))]),
+                ]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::SealMismatch; 4]);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[3].casts[0].seals[0]");
+        assert_eq!(
+            diagnostics[0].message,
+            "default Int(5000) of cast 'energy' is outside its Range { min: Some(Int(0)), max: Some(Int(1000)) } seal"
+        );
+        assert_eq!(diagnostics[2].message, "default Str(\"toolong\") of cast 'code' is outside its Length { min: Some(2), max: Some(4) } seal");
+    }
+
+    #[test]
+    fn test_alias_errors() {
+        let ir = IR {
+            alveolus: vec![
+                alias("Email", Flora::Str, vec![Seal::Range { min: Some(Literal::Int(0)), max: None }, Seal::Core]),
+                alias("Ping", Flora::List(Box::new(Flora::Bug("Pong".to_string()))), vec![]),
+                alias("Pong", Flora::Bug("Ping".to_string()), vec![]),
+                alias("Email", Flora::Bug("Mail".to_string()), vec![]),
+                larvie("Bird", vec![cast("mail", Flora::Bug("Email".to_string()), vec![Seal::Default(Literal::Int(1))])]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![
+            Code::DuplicateAlias,
+            Code::SealMismatch,
+            Code::InvalidSeal,
+            Code::AliasCycle,
+            Code::AliasCycle,
+            Code::UnknownBug,
+            Code::SealMismatch,
+        ]);
+        assert_eq!(diagnostics[1].message, "Range seal does not apply to alias 'Email' of Str");
+        assert_eq!(diagnostics[3].path.to_string(), "alveolus[1].flora");
+        assert_eq!(diagnostics[6].message, "default Int(1) does not fit cast 'mail' of Str");
+    }
+
+    fn related(primor: &str, relations: Vec<Relation>) -> Alveolus {
+        Alveolus::Larvie(Larvie {
+            primor: primor.to_string(),
+            casts: vec![cast("id", Flora::Uuid, vec![Seal::Core])],
+            relations,
+            ..Default::default()
+        })
+    }
+
+    fn relation(primor: &str, target: &str, cardinality: Cardinality, inverse: Option<&str>) -> Relation {
+        Relation { inverse: inverse.map(str::to_string), ..Relation::new(primor, target, cardinality) }
+    }
+
+    #[test]
+    fn test_valid_relations() {
+        let ir = IR {
+            alveolus: vec![
+                related("Hive", vec![relation("bees", "Bee", Cardinality::OneToMany, Some("hive"))]),
+                related("Bee", vec![
+                    relation("hive", "Hive", Cardinality::ManyToOne, Some("bees")),
+                    relation("friends", "Bee", Cardinality::ManyToMany, None),
+                ]),
+            ],
+        };
+
+        assert!(ir.validate().is_empty());
+    }
+
+    #[test]
+    fn test_relation_errors() {
+        let ir = IR {
+            alveolus: vec![
+                related("Hive", vec![
+                    relation("bees", "Bee", Cardinality::OneToMany, Some("hive")),
+                    relation("id", "Bee", Cardinality::OneToOne, None),
+                    relation("color", "Color", Cardinality::ManyToOne, None),
+                    relation("queen", "Queen", Cardinality::OneToOne, None),
+                    relation("drones", "Bee", Cardinality::OneToMany, Some("id")),
+                    relation("workers", "Bee", Cardinality::OneToMany, Some("swarm")),
+                    relation("color", "Bee", Cardinality::ManyToOne, None),
+                ]),
+                related("Bee", vec![relation("hive", "Hive", Cardinality::OneToOne, Some("bees"))]),
+                color(vec![member("Red", None)]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![
+            Code::InvalidRelation,
+            Code::DuplicateRelation,
+            Code::InvalidRelation,
+            Code::InvalidRelation,
+            Code::InvalidRelation,
+            Code::InvalidRelation,
+            Code::DuplicateRelation,
+            Code::InvalidRelation,
+        ]);
+        assert_eq!(diagnostics[0].message, "relation 'Hive.bees' is OneToMany but its inverse 'Bee.hive' is OneToOne");
+        assert_eq!(diagnostics[1].message, "relation 'id' clashes with a cast of the same name in larvie 'Hive'");
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[0].relations[0].inverse");
+        assert_eq!(diagnostics[2].message, "relation 'color' targets enumeration 'Color'; only larvies can be related");
+        assert_eq!(diagnostics[3].message, "relation 'queen' targets unknown larvie 'Queen'");
+        assert_eq!(diagnostics[4].message, "inverse 'id' of relation 'drones' clashes with a cast of larvie 'Bee'");
+        assert_eq!(diagnostics[5].message, "inverse 'swarm' of relation 'workers' is not a relation of larvie 'Bee'");
+        assert_eq!(diagnostics[6].message, "relation 'color' is declared more than once in larvie 'Hive'");
+    }
+
+    fn computed(primor: &str, flora: Flora, seals: Vec<Seal>, expr: Expr) -> Casts {
+        Casts { computed: Some(expr), ..cast(primor, flora, seals) }
+    }
+
+    fn read(primor: &str) -> Box<Expr> {
+        Box::new(Expr::Cast(primor.to_string()))
+    }
+
+    #[test]
+    fn test_valid_computed_casts() {
+        let ir = IR {
+            alveolus: vec![larvie("Bird", vec![
+                cast("born", Flora::Int, vec![Seal::Vital]),
+                computed("age", Flora::Int, vec![], Expr::Binary { op: BinOp::Sub, lhs: Box::new(Expr::Literal(Literal::Int(2026))), rhs: read("born") }),
+                computed("adult", Flora::Bool, vec![Seal::Vital], Expr::Binary { op: BinOp::Ge, lhs: read("age"), rhs: Box::new(Expr::Literal(Literal::Int(1))) }),
+            ])],
+        };
+
+        assert!(ir.validate().is_empty());
+    }
+
+    #[test]
+    fn test_computed_cast_errors() {
+        let mut ir = IR {
+            alveolus: vec![Alveolus::Larvie(Larvie {
+                primor: "Bird".to_string(),
+                casts: vec![
+                    cast("name", Flora::Str, vec![]),
+                    computed("id", Flora::Int, vec![Seal::Core], Expr::Literal(Literal::Int(1))),
+                    computed("size", Flora::Int, vec![], Expr::Unary { op: UnOp::Neg, operand: read("name") }),
+                    computed("loud", Flora::Bool, vec![], Expr::Call { instinct: "sing".to_string(), args: vec![] }),
+                    computed("ping", Flora::Int, vec![], Expr::Cast("pong".to_string())),
+                    computed("pong", Flora::Int, vec![], Expr::Cast("ping".to_string())),
+                ],
+                indexes: vec![Index { primor: "by_size".to_string(), parts: vec![IndexPart { cast: "size".to_string(), order: Order::Asc }], ..Default::default() }],
+                ..Default::default()
+            })],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![
+            Code::InvalidComputed,
+            Code::TypeMismatch,
+            Code::InvalidComputed,
+            Code::InvalidComputed,
+            Code::InvalidComputed,
+            Code::InvalidConstraint,
+        ]);
+        assert_eq!(diagnostics[0].message, "computed cast 'id' is never stored and cannot be Core");
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[0].casts[1].seals[0]");
+        assert_eq!(diagnostics[1].path.to_string(), "alveolus[0].casts[2].computed");
+        assert_eq!(diagnostics[2].message, "computed cast 'loud' may only read sibling casts and literals");
+        assert_eq!(diagnostics[3].message, "computed cast 'ping' depends on itself");
+        assert_eq!(diagnostics[5].message, "computed cast 'size' cannot be part of a key or index");
+
+        let Alveolus::Larvie(ref mut bird) = ir.alveolus[0] else { panic!("expected a larvie") };
+        bird.casts.truncate(1);
+        bird.casts.push(computed("label", Flora::Str, vec![], Expr::Cast("name".to_string())));
+        bird.indexes.clear();
+        bird.instincts.push(Instinct {
+            echo: "rename".to_string(),
+            body: vec![Stmt::Assign { cast: "label".to_string(), value: Expr::Literal(Literal::Str("Tweety".to_string())) }],
+            ..Default::default()
+        });
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::TypeMismatch]);
+        assert_eq!(diagnostics[0].message, "cast 'label' is computed and cannot be assigned");
+    }
+
+    #[test]
+    fn test_modules_are_left_to_link() {
+        let billing = || Alveolus::Module(Module {
+            primor: "billing".to_string(),
+            alveolus: vec![larvie("Invoice", vec![cast("payer", Flora::Bug("Payer".to_string()), vec![])])],
+            ..Default::default()
+        });
+        let ir = IR { alveolus: vec![billing(), billing()] };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::DuplicateModule]);
+        assert_eq!(diagnostics[0].message, "module 'billing' is already defined at alveolus[0]");
+    }
+
+    fn bee() -> Alveolus {
+        Alveolus::Larvie(Larvie {
+            primor: "Bee".to_string(),
+            instincts: ["hatch", "molt", "sting"].iter().map(|echo| Instinct { echo: echo.to_string(), ..Default::default() }).collect(),
+            ..Default::default()
+        })
+    }
+
+    fn state(primor: &str, terminal: bool) -> State {
+        State { primor: primor.to_string(), terminal, ..Default::default() }
+    }
+
+    fn transition(from: &str, to: &str, instinct: &str) -> Transition {
+        Transition { from: from.to_string(), to: to.to_string(), instinct: instinct.to_string(), ..Default::default() }
+    }
+
+    fn growth(states: Vec<State>, transitions: Vec<Transition>) -> Alveolus {
+        Alveolus::Lifecycle(Lifecycle {
+            primor: "Growth".to_string(),
+            larvie: "Bee".to_string(),
+            initial: "Egg".to_string(),
+            states,
+            transitions,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_valid_lifecycle() {
+        let ir = IR {
+            alveolus: vec![
+                bee(),
+                growth(
+                    vec![state("Egg", false), state("Larva", false), state("Adult", true)],
+                    vec![transition("Egg", "Larva", "hatch"), transition("Larva", "Adult", "molt"), transition("Adult", "Adult", "sting")],
+                ),
+            ],
+        };
+
+        assert!(ir.validate().is_empty());
+    }
+
+    #[test]
+    fn test_lifecycle_errors() {
+        let ir = IR {
+            alveolus: vec![
+                bee(),
+                growth(
+                    vec![state("Egg", false), state("Larva", false), state("Pupa", false), state("Adult", true), state("Larva", false)],
+                    vec![
+                        transition("Egg", "Larva", "hatch"),
+                        transition("Egg", "Adult", "hatch"),
+                        transition("Larva", "Queen", "crown"),
+                    ],
+                ),
+                Alveolus::Lifecycle(Lifecycle {
+                    primor: "Season".to_string(),
+                    larvie: "Color".to_string(),
+                    initial: "Spring".to_string(),
+                    states: vec![state("Summer", true)],
+                    ..Default::default()
+                }),
+                color(vec![member("Red", None)]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![
+            Code::DuplicateState,
+            Code::InvalidLifecycle,
+            Code::UnknownState,
+            Code::UnknownInstinct,
+            Code::UnreachableState,
+            Code::DeadState,
+            Code::InvalidLifecycle,
+            Code::UnknownState,
+        ]);
+        assert_eq!(diagnostics[1].message, "instinct 'hatch' leaves state 'Egg' through more than one transition");
+        assert_eq!(diagnostics[2].message, "transition 'crown' uses unknown state 'Queen' of lifecycle 'Growth'");
+        assert_eq!(diagnostics[3].path.to_string(), "alveolus[1].transitions[2].instinct");
+        assert_eq!(diagnostics[4].message, "state 'Pupa' of lifecycle 'Growth' cannot be reached from 'Egg'");
+        assert_eq!(diagnostics[5].message, "state 'Pupa' of lifecycle 'Growth' has no way out and is not terminal");
+        assert_eq!(diagnostics[6].message, "lifecycle 'Season' runs over enumeration 'Color'; only larvies have lifecycles");
+    }
+
+    #[test]
+    fn test_emitted_events() {
+        let ir = IR {
+            alveolus: vec![
+                Alveolus::Event(Event {
+                    primor: "Hatched".to_string(),
+                    casts: vec![
+                        cast("at", Flora::DateTime, vec![Seal::Vital]),
+                        cast("egg", Flora::Bug("Egg".to_string()), vec![Seal::Core]),
+                        cast("at", Flora::Date, vec![]),
+                    ],
+                    ..Default::default()
+                }),
+                Alveolus::Larvie(Larvie {
+                    primor: "Egg".to_string(),
+                    instincts: vec![Instinct {
+                        echo: "hatch".to_string(),
+                        emits: vec!["Hatched".to_string(), "Cracked".to_string(), "Color".to_string()],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }),
+                color(vec![member("Red", None)]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::InvalidSeal, Code::DuplicateCast, Code::UnknownEvent, Code::UnknownEvent]);
+        assert_eq!(diagnostics[0].message, "Core seal does not apply to cast 'egg' of event 'Hatched'");
+        assert_eq!(diagnostics[2].message, "instinct 'hatch' emits unknown event 'Cracked'");
+        assert_eq!(diagnostics[2].path.to_string(), "alveolus[1].instincts[0].emits[1]");
+        assert_eq!(diagnostics[3].message, "instinct 'hatch' emits enumeration 'Color', which is not an event");
+    }
+
+    fn public_bird(selects: Vec<Selection>, computed: Vec<Casts>) -> Alveolus {
+        Alveolus::View(View { primor: "PublicBird".to_string(), source: "Bird".to_string(), selects, computed, ..Default::default() })
+    }
+
+    fn select(cast: &str, rename: Option<&str>) -> Selection {
+        Selection { cast: cast.to_string(), rename: rename.map(str::to_string), ..Default::default() }
+    }
+
+    #[test]
+    fn test_valid_view() {
+        let ir = IR {
+            alveolus: vec![
+                larvie("Bird", vec![cast("name", Flora::Str, vec![Seal::Vital]), cast("energy", Flora::Int, vec![])]),
+                public_bird(
+                    vec![select("name", Some("title"))],
+                    vec![computed("energy", Flora::Int, vec![], Expr::Binary { op: BinOp::Div, lhs: read("energy"), rhs: Box::new(Expr::Literal(Literal::Int(10))) })],
+                ),
+            ],
+        };
+
+        assert!(ir.validate().is_empty());
+    }
+
+    #[test]
+    fn test_view_errors() {
+        let ir = IR {
+            alveolus: vec![
+                larvie("Bird", vec![cast("name", Flora::Str, vec![]), cast("energy", Flora::Int, vec![])]),
+                public_bird(
+                    vec![select("name", None), select("wings", None), select("energy", Some("name"))],
+                    vec![
+                        computed("loud", Flora::Bool, vec![], Expr::Cast("energy".to_string())),
+                        cast("title", Flora::Str, vec![]),
+                    ],
+                ),
+                Alveolus::View(View { primor: "Palette".to_string(), source: "Color".to_string(), ..Default::default() }),
+                Alveolus::View(View { primor: "Empty".to_string(), source: "Bird".to_string(), ..Default::default() }),
+                color(vec![member("Red", None)]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![
+            Code::UnknownCast,
+            Code::DuplicateCast,
+            Code::TypeMismatch,
+            Code::InvalidView,
+            Code::InvalidView,
+            Code::InvalidView,
+        ]);
+        assert_eq!(diagnostics[0].message, "larvie 'Bird' has no cast 'wings' for view 'PublicBird'");
+        assert_eq!(diagnostics[1].message, "cast 'name' appears more than once in view 'PublicBird'");
+        assert_eq!(diagnostics[2].path.to_string(), "alveolus[1].computed[0].computed");
+        assert_eq!(diagnostics[3].message, "cast 'title' of view 'PublicBird' is neither selected nor computed");
+        assert_eq!(diagnostics[4].message, "view 'Palette' projects enumeration 'Color'; only larvies can be projected");
+        assert_eq!(diagnostics[5].message, "view 'Empty' exposes no casts");
+    }
+
+    #[test]
+    fn test_only_data_alveolus_are_floras() {
+        let bug = |name: &str| Flora::Bug(name.to_string());
+        let ir = IR {
+            alveolus: vec![
+                bee(),
+                growth(vec![state("Egg", true)], vec![]),
+                larvie("Bird", vec![cast("name", Flora::Str, vec![])]),
+                public_bird(vec![select("name", None)], vec![]),
+                Alveolus::Module(Module { primor: "billing".to_string(), ..Default::default() }),
+                larvie("Nest", vec![
+                    cast("stage", bug("Growth"), vec![]),
+                    cast("owners", Flora::List(Box::new(bug("PublicBird"))), vec![]),
+                    cast("ledger", bug("billing"), vec![]),
+                ]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::InvalidLifecycle, Code::InvalidView, Code::InvalidModule]);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[5].casts[0].flora");
+        assert_eq!(diagnostics[1].message, "cast 'owners' uses view 'PublicBird' as a flora; views are read-only projections of a larvie");
+        assert_eq!(diagnostics[2].message, "cast 'ledger' uses module 'billing' as a flora; modules only group alveolus");
+    }
+
+    #[test]
+    fn test_embedding_cycle_checks() {
+        let reference = |larvie: &str| Seal::Reference {
+            larvie: larvie.to_string(),
+            cast: "id".to_string(),
+            on_delete: ReferentialAction::NoAction,
+            on_update: ReferentialAction::NoAction,
+        };
+        let ir = IR {
+            alveolus: vec![
+                Alveolus::Union(Union {
+                    primor: "Egg".to_string(),
+                    variants: vec![variant("Laid", vec![cast("chick", Flora::Bug("Chick".to_string()), vec![])])],
+                    ..Default::default()
+                }),
+                Alveolus::Union(Union {
+                    primor: "Chick".to_string(),
+                    variants: vec![variant("Hatched", vec![cast("egg", Flora::Bug("Egg".to_string()), vec![])])],
+                    ..Default::default()
+                }),
+                Alveolus::Union(Union {
+                    primor: "Worm".to_string(),
+                    variants: vec![variant("Segment", vec![cast("rest", Flora::Bug("Worm".to_string()), vec![])]), variant("Tail", vec![])],
+                    ..Default::default()
+                }),
+                larvie("Bird", vec![
+                    cast("id", Flora::Uuid, vec![Seal::Core]),
+                    cast("nest", Flora::Bug("Nest".to_string()), vec![reference("Nest")]),
+                    cast("mate", Flora::Bug("Bird".to_string()), vec![]),
+                ]),
+                larvie("Nest", vec![cast("id", Flora::Uuid, vec![Seal::Core]), cast("owner", Flora::Bug("Bird".to_string()), vec![reference("Bird")])]),
+                alias("Ring", Flora::Bug("Loop".to_string()), vec![]),
+                alias("Loop", Flora::Bug("Ring".to_string()), vec![]),
+            ],
+        };
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(codes(&ir), vec![Code::AliasCycle, Code::AliasCycle, Code::EmbeddingCycle, Code::EmbeddingCycle]);
+        assert_eq!(diagnostics[2].message, "'Egg', 'Chick' embed each other by value; put one of them behind Optional, List or a reference");
+        assert_eq!(diagnostics[2].path.to_string(), "alveolus[0].variants[0].casts[0].flora");
+        assert_eq!(diagnostics[3].message, "union 'Worm' embeds itself by value; put it behind Optional, List or a reference");
+    }
+
+    #[test]
+    fn test_bodies_see_through_aliases() {
+        let energy = || Flora::Bug("Energy".to_string());
+        let ir = IR {
+            alveolus: vec![
+                alias("Energy", Flora::U16, vec![]),
+                Alveolus::Larvie(Larvie {
+                    primor: "Bird".to_string(),
+                    casts: vec![
+                        cast("energy", energy(), vec![]),
+                        Casts {
+                            computed: Some(Expr::Binary {
+                                op: BinOp::Lt,
+                                lhs: Box::new(Expr::Cast("energy".to_string())),
+                                rhs: Box::new(Expr::Literal(Literal::Int(5))),
+                            }),
+                            ..Casts::new("tired", Flora::Bool)
+                        },
+                    ],
+                    instincts: vec![Instinct {
+                        echo: "rest".to_string(),
+                        params: vec![Param::new("hours", energy())],
+                        returns: Some(energy()),
+                        body: vec![
+                            Stmt::Assign { cast: "energy".to_string(), value: Expr::Literal(Literal::Int(10)) },
+                            Stmt::Assign {
+                                cast: "energy".to_string(),
+                                value: Expr::Binary {
+                                    op: BinOp::Add,
+                                    lhs: Box::new(Expr::Cast("energy".to_string())),
+                                    rhs: Box::new(Expr::Param("hours".to_string())),
+                                },
+                            },
+                            Stmt::Return(Some(Expr::Cast("energy".to_string()))),
+                        ],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }),
+            ],
+        };
+
+        assert_eq!(codes(&ir), vec![]);
+    }
+}
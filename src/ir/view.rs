@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ir::{IR, alveolus::Alveolus, attribute::Attributes, casts::Casts, span::Span};
+
+/// A projection of the `source` larvie, such as an API shape: some of its casts, possibly
+/// renamed, followed by casts computed from the source's casts.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct View {
+    pub primor: String,
+    pub source: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub selects: Vec<Selection>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub computed: Vec<Casts>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+/// A source cast exposed by a view, under `rename` if given.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Selection {
+    pub cast: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rename: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+impl Selection {
+    /// The name the cast has in the view.
+    pub fn primor(&self) -> &str {
+        self.rename.as_deref().unwrap_or(&self.cast)
+    }
+}
+
+impl IR {
+    pub fn view(&self, name: &str) -> Option<&View> {
+        self.alveolus.iter().find_map(|alveolus| match alveolus {
+            Alveolus::View(view) if view.primor == name => Some(view),
+            _ => None,
+        })
+    }
+
+    /// The casts `view` exposes, in order: each selected source cast under its view name, then
+    /// the computed ones. Selections of missing casts are skipped; `None` if the source is no larvie.
+    ///
+    /// Selected casts keep their source doc and attributes unless the selection sets its own.
+    pub fn view_casts(&self, view: &View) -> Option<Vec<Casts>> {
+        let source = self.alveolus.iter().find_map(|alveolus| match alveolus {
+            Alveolus::Larvie(larvie) if larvie.primor == view.source => Some(larvie),
+            _ => None,
+        })?;
+
+        let mut casts = Vec::new();
+        for selection in &view.selects {
+            let Some(cast) = source.cast(&selection.cast) else { continue };
+            let mut cast = Casts { primor: selection.primor().to_string(), ..cast.clone() };
+            if selection.doc.is_some() {
+                cast.doc = selection.doc.clone();
+            }
+            cast.attributes.extend(selection.attributes.clone());
+            casts.push(cast);
+        }
+        casts.extend(view.computed.iter().cloned());
+        Some(casts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{body::Expr, flora::Flora, larvie::Larvie, literal::Literal, seal::Seal};
+    use serde_json;
+
+    fn select(cast: &str, rename: Option<&str>) -> Selection {
+        Selection { cast: cast.to_string(), rename: rename.map(str::to_string), ..Default::default() }
+    }
+
+    fn public_bird() -> View {
+        View {
+            primor: "PublicBird".to_string(),
+            source: "Bird".to_string(),
+            selects: vec![select("id", None), select("name", Some("display_name")), select("wings", None)],
+            computed: vec![Casts {
+                computed: Some(Expr::Literal(Literal::Bool(true))),
+                ..Casts::new("flying", Flora::Bool)
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_view_json_format() {
+        let view = View { primor: "PublicBird".to_string(), source: "Bird".to_string(), selects: vec![select("name", Some("title"))], ..Default::default() };
+
+        let json = serde_json::to_string(&view).unwrap();
+
+        assert_eq!(json, "{\"primor\":\"PublicBird\",\"source\":\"Bird\",\"selects\":[{\"cast\":\"name\",\"rename\":\"title\"}]}");
+        assert_eq!(serde_json::from_str::<View>(&json).unwrap(), view);
+    }
+
+    #[test]
+    fn test_view_casts() {
+        let ir = IR {
+            alveolus: vec![
+                Alveolus::Larvie(Larvie {
+                    primor: "Bird".to_string(),
+                    casts: vec![
+                        Casts { seals: vec![Seal::Core], ..Casts::new("id", Flora::Uuid) },
+                        Casts { doc: Some("Common name.".to_string()), ..Casts::new("name", Flora::Str) },
+                        Casts::new("energy", Flora::U16),
+                    ],
+                    ..Default::default()
+                }),
+                Alveolus::View(public_bird()),
+            ],
+        };
+
+        let casts = ir.view_casts(ir.view("PublicBird").unwrap()).unwrap();
+
+        let primors: Vec<&str> = casts.iter().map(|cast| cast.primor.as_str()).collect();
+        assert_eq!(primors, vec!["id", "display_name", "flying"]);
+        assert_eq!(casts[0].seals, vec![Seal::Core]);
+        assert_eq!(casts[1].doc.as_deref(), Some("Common name."));
+        assert!(!casts[2].is_stored());
+        assert_eq!(ir.view_casts(&View { source: "Nest".to_string(), ..public_bird() }), None);
+    }
+}
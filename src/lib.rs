@@ -1,11 +1,33 @@
+pub mod dsl;
 pub mod ir;
 
 pub use ir::{
     IR,
+    parse,
+    parse_file,
+    sc_tree_to_ir,
     alveolus::Alveolus,
     larvie::Larvie,
     casts::Casts,
     flora::Flora,
-    seal::Seal,
-    instincts::Instinct,
+    seal::{ReferentialAction, Seal},
+    instincts::{Instinct, Param},
+    diagnostic::{Code, Diagnostic, Path, Severity},
+    span::Span,
+    literal::Literal,
+    constraint::{Constraint, Index, IndexPart, Order},
+    body::{BinOp, Expr, Stmt, UnOp},
+    enumeration::{Enumeration, Member},
+    union::{Union, Variant},
+    interface::Interface,
+    mixin::Mixin,
+    alias::{Alias, AliasKind},
+    relation::{Cardinality, Relation},
+    attribute::{Annotated, Attributes},
+    module::{Import, Module},
+    lifecycle::{Lifecycle, State, Transition},
+    event::Event,
+    view::{Selection, View},
+    symbol::{Handle, Reference, Bindings, SymbolTable},
+    graph::{Dependency, DependencyGraph, DependencyKind},
 };
@@ -4,7 +4,6 @@
 //! the integrity of the IR library as an independent component.
 
 use ::ir::*;
-use serde_json;
 
 #[cfg(test)]
 mod ir_integrity_tests {
@@ -26,16 +25,17 @@ mod ir_integrity_tests {
             primor: "User".to_string(),
             casts: vec![
                 Casts {
-                    primor: "id".to_string(),
-                    flora: Flora::Int,
                     seals: vec![Seal::Vital, Seal::Core],
+                    ..Casts::new("id", Flora::Int)
                 }
             ],
             instincts: vec![
                 Instinct {
                     echo: "create".to_string(),
+                    ..Default::default()
                 }
             ],
+            ..Default::default()
         };
 
         let ir = IR {
@@ -43,7 +43,7 @@ mod ir_integrity_tests {
         };
 
         assert_eq!(ir.alveolus.len(), 1);
-        let Alveolus::Larvie(ref stored_larvie) = ir.alveolus[0];
+        let Alveolus::Larvie(ref stored_larvie) = ir.alveolus[0] else { panic!("expected a larvie") };
         assert_eq!(stored_larvie.primor, "User");
         assert_eq!(stored_larvie.casts.len(), 1);
         assert_eq!(stored_larvie.instincts.len(), 1);
@@ -55,39 +55,37 @@ mod ir_integrity_tests {
             primor: "User".to_string(),
             casts: vec![
                 Casts {
-                    primor: "id".to_string(),
-                    flora: Flora::Int,
                     seals: vec![Seal::Core],
+                    ..Casts::new("id", Flora::Int)
                 },
                 Casts {
-                    primor: "name".to_string(),
-                    flora: Flora::Str,
                     seals: vec![Seal::Vital],
+                    ..Casts::new("name", Flora::Str)
                 },
             ],
             instincts: vec![
-                Instinct { echo: "create".to_string() },
-                Instinct { echo: "update".to_string() },
+                Instinct { echo: "create".to_string(), ..Default::default() },
+                Instinct { echo: "update".to_string(), ..Default::default() },
             ],
+            ..Default::default()
         };
 
         let product_larvie = Larvie {
             primor: "Product".to_string(),
             casts: vec![
                 Casts {
-                    primor: "price".to_string(),
-                    flora: Flora::Int,
                     seals: vec![Seal::Vital],
+                    ..Casts::new("price", Flora::Int)
                 },
                 Casts {
-                    primor: "available".to_string(),
-                    flora: Flora::Bool,
                     seals: vec![Seal::Root],
+                    ..Casts::new("available", Flora::Bool)
                 },
             ],
             instincts: vec![
-                Instinct { echo: "sell".to_string() },
+                Instinct { echo: "sell".to_string(), ..Default::default() },
             ],
+            ..Default::default()
         };
 
         let ir = IR {
@@ -106,14 +104,14 @@ mod ir_integrity_tests {
             primor: "TestEntity".to_string(),
             casts: vec![
                 Casts {
-                    primor: "field1".to_string(),
-                    flora: Flora::Str,
                     seals: vec![Seal::Vital],
+                    ..Casts::new("field1", Flora::Str)
                 }
             ],
             instincts: vec![
-                Instinct { echo: "action1".to_string() }
+                Instinct { echo: "action1".to_string(), ..Default::default() }
             ],
+            ..Default::default()
         };
 
         let ir = IR {
@@ -139,14 +137,14 @@ mod ir_integrity_tests {
                     primor: "Beautiful".to_string(),
                     casts: vec![
                         Casts {
-                            primor: "elegant".to_string(),
-                            flora: Flora::Bool,
                             seals: vec![Seal::Core, Seal::Root],
+                            ..Casts::new("elegant", Flora::Bool)
                         }
                     ],
                     instincts: vec![
-                        Instinct { echo: "shine".to_string() }
+                        Instinct { echo: "shine".to_string(), ..Default::default() }
                     ],
+                    ..Default::default()
                 })
             ]
         };
@@ -269,9 +267,8 @@ mod casts_tests {
     #[test]
     fn test_casts_creation() {
         let cast = Casts {
-            primor: "test_field".to_string(),
-            flora: Flora::Int,
             seals: vec![Seal::Vital],
+            ..Casts::new("test_field", Flora::Int)
         };
 
         assert_eq!(cast.primor, "test_field");
@@ -283,9 +280,8 @@ mod casts_tests {
     #[test]
     fn test_casts_with_multiple_seals() {
         let cast = Casts {
-            primor: "complex_field".to_string(),
-            flora: Flora::Str,
             seals: vec![Seal::Vital, Seal::Core, Seal::Root],
+            ..Casts::new("complex_field", Flora::Str)
         };
 
         assert_eq!(cast.seals.len(), 3);
@@ -297,9 +293,8 @@ mod casts_tests {
     #[test]
     fn test_casts_with_no_seals() {
         let cast = Casts {
-            primor: "optional_field".to_string(),
-            flora: Flora::Bool,
             seals: vec![],
+            ..Casts::new("optional_field", Flora::Bool)
         };
 
         assert_eq!(cast.seals.len(), 0);
@@ -308,9 +303,8 @@ mod casts_tests {
     #[test]
     fn test_casts_serialization() {
         let cast = Casts {
-            primor: "serializable_field".to_string(),
-            flora: Flora::Bug("Roach".to_string()),
             seals: vec![Seal::Core],
+            ..Casts::new("serializable_field", Flora::Bug("Roach".to_string()))
         };
 
         let json = serde_json::to_string(&cast).unwrap();
@@ -327,9 +321,8 @@ mod casts_tests {
 
         for flora in flora_types {
             let cast = Casts {
-                primor: format!("field_{:?}", flora),
-                flora: flora.clone(),
                 seals: vec![Seal::Vital],
+                ..Casts::new(format!("field_{:?}", flora), flora.clone())
             };
 
             assert_eq!(cast.flora, flora);
@@ -345,6 +338,7 @@ mod instinct_tests {
     fn test_instinct_creation() {
         let instinct = Instinct {
             echo: "test_action".to_string(),
+            ..Default::default()
         };
 
         assert_eq!(instinct.echo, "test_action");
@@ -354,6 +348,7 @@ mod instinct_tests {
     fn test_instinct_serialization() {
         let instinct = Instinct {
             echo: "serializable_action".to_string(),
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&instinct).unwrap();
@@ -366,6 +361,7 @@ mod instinct_tests {
     fn test_instinct_clone_and_eq() {
         let original = Instinct {
             echo: "original_action".to_string(),
+            ..Default::default()
         };
 
         let cloned = original.clone();
@@ -373,6 +369,7 @@ mod instinct_tests {
 
         let different = Instinct {
             echo: "different_action".to_string(),
+            ..Default::default()
         };
         assert_ne!(original, different);
     }
@@ -381,6 +378,7 @@ mod instinct_tests {
     fn test_instinct_empty_echo() {
         let instinct = Instinct {
             echo: "".to_string(),
+            ..Default::default()
         };
 
         assert_eq!(instinct.echo, "");
@@ -401,6 +399,7 @@ mod larvie_tests {
             primor: "TestLarvie".to_string(),
             casts: vec![],
             instincts: vec![],
+            ..Default::default()
         };
 
         assert_eq!(larvie.primor, "TestLarvie");
@@ -414,20 +413,19 @@ mod larvie_tests {
             primor: "ComplexLarvie".to_string(),
             casts: vec![
                 Casts {
-                    primor: "field1".to_string(),
-                    flora: Flora::Int,
                     seals: vec![Seal::Vital],
+                    ..Casts::new("field1", Flora::Int)
                 },
                 Casts {
-                    primor: "field2".to_string(),
-                    flora: Flora::Str,
                     seals: vec![Seal::Core],
+                    ..Casts::new("field2", Flora::Str)
                 },
             ],
             instincts: vec![
-                Instinct { echo: "action1".to_string() },
-                Instinct { echo: "action2".to_string() },
+                Instinct { echo: "action1".to_string(), ..Default::default() },
+                Instinct { echo: "action2".to_string(), ..Default::default() },
             ],
+            ..Default::default()
         };
 
         assert_eq!(larvie.casts.len(), 2);
@@ -442,14 +440,14 @@ mod larvie_tests {
             primor: "SerializableLarvie".to_string(),
             casts: vec![
                 Casts {
-                    primor: "test_field".to_string(),
-                    flora: Flora::Bool,
                     seals: vec![Seal::Root],
+                    ..Casts::new("test_field", Flora::Bool)
                 }
             ],
             instincts: vec![
-                Instinct { echo: "test_action".to_string() }
+                Instinct { echo: "test_action".to_string(), ..Default::default() }
             ],
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&larvie).unwrap();
@@ -471,11 +469,12 @@ mod alveolus_tests {
             primor: "TestLarvie".to_string(),
             casts: vec![],
             instincts: vec![],
+            ..Default::default()
         };
 
         let alveolus = Alveolus::Larvie(larvie.clone());
 
-        let Alveolus::Larvie(ref stored_larvie) = alveolus;
+        let Alveolus::Larvie(ref stored_larvie) = alveolus else { panic!("expected a larvie") };
         assert_eq!(stored_larvie.primor, "TestLarvie");
     }
 
@@ -485,12 +484,12 @@ mod alveolus_tests {
             primor: "SerializableLarvie".to_string(),
             casts: vec![
                 Casts {
-                    primor: "test".to_string(),
-                    flora: Flora::Int,
                     seals: vec![Seal::Vital],
+                    ..Casts::new("test", Flora::Int)
                 }
             ],
             instincts: vec![],
+            ..Default::default()
         };
 
         let alveolus = Alveolus::Larvie(larvie);
@@ -498,7 +497,7 @@ mod alveolus_tests {
         let json = serde_json::to_string(&alveolus).unwrap();
         let deserialized: Alveolus = serde_json::from_str(&json).unwrap();
 
-        let (Alveolus::Larvie(original), Alveolus::Larvie(deserialized)) = (&alveolus, &deserialized);
+        let (Alveolus::Larvie(original), Alveolus::Larvie(deserialized)) = (&alveolus, &deserialized) else { panic!("expected a larvie") };
         assert_eq!(original.primor, deserialized.primor);
     }
 
@@ -508,6 +507,7 @@ mod alveolus_tests {
             primor: "CloneTest".to_string(),
             casts: vec![],
             instincts: vec![],
+            ..Default::default()
         };
 
         let original = Alveolus::Larvie(larvie);
@@ -529,28 +529,28 @@ mod integration_edge_cases {
                     primor: "Entity1".to_string(),
                     casts: vec![
                         Casts {
-                            primor: "field_with_all_seals".to_string(),
-                            flora: Flora::Int,
                             seals: vec![Seal::Vital, Seal::Core, Seal::Root],
+                            ..Casts::new("field_with_all_seals", Flora::Int)
                         },
                         Casts {
-                            primor: "another_field".to_string(),
-                            flora: Flora::Bug("Fox".to_string()),
                             seals: vec![],
+                            ..Casts::new("another_field", Flora::Bug("Fox".to_string()))
                         },
                     ],
                     instincts: vec![
-                        Instinct { echo: "complex_action".to_string() },
+                        Instinct { echo: "complex_action".to_string(), ..Default::default() },
                     ],
+                    ..Default::default()
                 }),
                 Alveolus::Larvie(Larvie {
                     primor: "Entity2".to_string(),
                     casts: vec![],
                     instincts: vec![
-                        Instinct { echo: "action1".to_string() },
-                        Instinct { echo: "action2".to_string() },
-                        Instinct { echo: "action3".to_string() },
+                        Instinct { echo: "action1".to_string(), ..Default::default() },
+                        Instinct { echo: "action2".to_string(), ..Default::default() },
+                        Instinct { echo: "action3".to_string(), ..Default::default() },
                     ],
+                    ..Default::default()
                 }),
             ]
         };
@@ -571,14 +571,14 @@ mod integration_edge_cases {
                     primor: "Usuario_测试_🚀".to_string(),
                     casts: vec![
                         Casts {
-                            primor: "名前".to_string(),
-                            flora: Flora::Str,
                             seals: vec![Seal::Vital],
+                            ..Casts::new("名前", Flora::Str)
                         }
                     ],
                     instincts: vec![
-                        Instinct { echo: "создать_用户".to_string() }
+                        Instinct { echo: "создать_用户".to_string(), ..Default::default() }
                     ],
+                    ..Default::default()
                 })
             ]
         };
@@ -597,20 +597,20 @@ mod integration_edge_cases {
         // Create 100 fields and 50 actions
         for i in 0..100 {
             large_casts.push(Casts {
-                primor: format!("field_{}", i),
-                flora: match i % 4 {
+                seals: vec![Seal::Vital],
+                ..Casts::new(format!("field_{}", i), match i % 4 {
                     0 => Flora::Int,
                     1 => Flora::Str,
                     2 => Flora::Bool,
                     _ => Flora::Bug("Bee".to_string()),
-                },
-                seals: vec![Seal::Vital],
+                })
             });
         }
 
         for i in 0..50 {
             large_instincts.push(Instinct {
                 echo: format!("action_{}", i),
+                ..Default::default()
             });
         }
 
@@ -620,6 +620,7 @@ mod integration_edge_cases {
                     primor: "LargeEntity".to_string(),
                     casts: large_casts,
                     instincts: large_instincts,
+                    ..Default::default()
                 })
             ]
         };
@@ -629,8 +630,538 @@ mod integration_edge_cases {
         let deserialized: IR = serde_json::from_str(&json).unwrap();
 
         assert_eq!(deserialized.alveolus.len(), 1);
-        let Alveolus::Larvie(ref larvie) = deserialized.alveolus[0];
+        let Alveolus::Larvie(ref larvie) = deserialized.alveolus[0] else { panic!("expected a larvie") };
         assert_eq!(larvie.casts.len(), 100);
         assert_eq!(larvie.instincts.len(), 50);
     }
 }
+
+#[cfg(test)]
+mod frontend_tests {
+    use super::*;
+    use ::ir::dsl::Tree;
+
+    fn strip_spans(mut ir: IR) -> IR {
+        for alveolus in &mut ir.alveolus {
+            let Alveolus::Larvie(larvie) = alveolus else { continue };
+            larvie.span = None;
+            larvie.casts.iter_mut().for_each(|cast| cast.span = None);
+            for instinct in &mut larvie.instincts {
+                instinct.span = None;
+                instinct.params.iter_mut().for_each(|param| param.span = None);
+            }
+        }
+        ir
+    }
+
+    #[test]
+    fn test_readme_example() {
+        let tree = Tree::parse_input("
+            bug Bird
+                gene energy Int
+                ethics fly
+            end
+        ".to_string()).unwrap();
+
+        let ir = sc_tree_to_ir(tree);
+
+        assert_eq!(strip_spans(ir), IR {
+            alveolus: vec![
+                Alveolus::Larvie(Larvie {
+                    primor: "Bird".to_string(),
+                    casts: vec![
+                        Casts {
+                            seals: vec![Seal::Vital],
+                            ..Casts::new("energy", Flora::Int)
+                        }
+                    ],
+                    instincts: vec![
+                        Instinct { echo: "fly".to_string(), ..Default::default() }
+                    ],
+                    ..Default::default()
+                })
+            ]
+        });
+    }
+
+    #[test]
+    fn test_parse_source_to_json_roundtrip() {
+        let ir = parse("
+            # A tiny colony
+            bug Queen
+                gene name Str
+                gene fertile Bool
+                ethics lay_eggs
+            end
+            bug Worker
+                gene queen Queen
+                ethics forage
+                ethics defend
+            end
+        ").unwrap();
+
+        let json = serde_json::to_string(&ir).unwrap();
+        let deserialized: IR = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, ir);
+        assert_eq!(ir.alveolus.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_error_is_displayable() {
+        let error = parse("bug Bird gene energy Int").unwrap_err();
+
+        assert_eq!(error.to_string(), "1:25: expected 'gene', 'ethics' or 'end', found end of input");
+    }
+
+    #[test]
+    fn test_parse_collection_floras() {
+        let ir = parse("
+            bug Bee
+            end
+            bug Hive
+                gene bees List<Bee>
+                gene nickname Optional<Str>
+                gene honey Map<Str, Int>
+            end
+        ").unwrap();
+
+        let Alveolus::Larvie(ref hive) = ir.alveolus[1] else { panic!("expected a larvie") };
+        assert_eq!(hive.casts[0].flora, Flora::List(Box::new(Flora::Bug("Bee".to_string()))));
+        assert_eq!(hive.casts[1].flora, Flora::Optional(Box::new(Flora::Str)));
+        assert_eq!(hive.casts[2].flora, Flora::Map(Box::new(Flora::Str), Box::new(Flora::Int)));
+        assert!(ir.validate().is_empty());
+
+        let json = serde_json::to_string(&ir).unwrap();
+        assert_eq!(serde_json::from_str::<IR>(&json).unwrap(), ir);
+    }
+
+    #[test]
+    fn test_parse_instinct_signatures() {
+        let ir = parse("
+            bug Tree
+            end
+            bug Bird
+                gene energy Int
+                ethics fly(to Tree, height U16): Bool ! Tired, Grounded
+                ethics rest
+            end
+        ").unwrap();
+
+        let Alveolus::Larvie(ref bird) = strip_spans(ir.clone()).alveolus[1] else { panic!("expected a larvie") };
+        assert_eq!(bird.instincts[0], Instinct {
+            echo: "fly".to_string(),
+            params: vec![
+                Param::new("to", Flora::Bug("Tree".to_string())),
+                Param::new("height", Flora::U16),
+            ],
+            returns: Some(Flora::Bool),
+            faults: vec!["Tired".to_string(), "Grounded".to_string()],
+            ..Default::default()
+        });
+        assert_eq!(bird.instincts[1], Instinct { echo: "rest".to_string(), ..Default::default() });
+        assert!(ir.validate().is_empty());
+    }
+
+    #[test]
+    fn test_spans_point_back_to_source() {
+        let source = "bug Bird\n  gene energy Int\n  ethics fly\nend\n";
+        let ir = parse_file("bird.sc", source).unwrap();
+
+        let Alveolus::Larvie(ref larvie) = ir.alveolus[0] else { panic!("expected a larvie") };
+        let cast_span = larvie.casts[0].span.as_ref().unwrap();
+
+        assert_eq!(ir.alveolus[0].span().unwrap().to_string(), "bird.sc:1:1");
+        assert_eq!(cast_span.to_string(), "bird.sc:2:3");
+        assert_eq!(&source[cast_span.start..cast_span.end], "gene energy Int");
+        assert_eq!(larvie.instincts[0].span.as_ref().unwrap().line, 3);
+
+        let json = serde_json::to_string(&ir).unwrap();
+        let deserialized: IR = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, ir);
+    }
+}
+
+#[cfg(test)]
+mod validation_tests {
+    use super::*;
+
+    #[test]
+    fn test_parsed_model_with_errors() {
+        let ir = parse("
+            bug Bird
+                gene nest Nest
+                gene energy Int
+                gene energy Int
+            end
+            bug Bird
+            end
+        ").unwrap();
+
+        let diagnostics = ir.validate();
+        let codes: Vec<Code> = diagnostics.iter().map(|d| d.code).collect();
+
+        assert_eq!(codes, vec![Code::DuplicateLarvie, Code::UnknownBug, Code::DuplicateCast]);
+        assert!(diagnostics.iter().all(Diagnostic::is_error));
+    }
+
+    #[test]
+    fn test_diagnostics_serialize_to_json() {
+        let ir = parse("bug Bird gene nest Nest end").unwrap();
+
+        let json = serde_json::to_string(&ir.validate()).unwrap();
+        let deserialized: Vec<Diagnostic> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, ir.validate());
+        assert!(json.contains("\"UnknownBug\""));
+    }
+
+    #[test]
+    fn test_diagnostics_carry_source_spans() {
+        let ir = parse_file("bird.sc", "bug Bird\n  gene energy Int\n  gene energy Int\nend").unwrap();
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(diagnostics[0].span.as_ref().unwrap().to_string(), "bird.sc:3:3");
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "error[DuplicateCast] alveolus[0].casts[1] (bird.sc:3:3): cast 'energy' is declared more than once in larvie 'Bird'"
+        );
+    }
+
+    #[test]
+    fn test_instinct_body_attached_to_parsed_model() {
+        let mut ir = parse("bug Bird gene energy Int ethics rest(hours Int): Int end").unwrap();
+        let Alveolus::Larvie(ref mut bird) = ir.alveolus[0] else { panic!("expected a larvie") };
+        bird.instincts[0].body = vec![
+            Stmt::Assign {
+                cast: "energy".to_string(),
+                value: Expr::Binary {
+                    op: BinOp::Add,
+                    lhs: Box::new(Expr::Cast("energy".to_string())),
+                    rhs: Box::new(Expr::Param("hours".to_string())),
+                },
+            },
+            Stmt::Return(Some(Expr::Cast("energy".to_string()))),
+        ];
+
+        let json = serde_json::to_string(&ir).unwrap();
+        let deserialized: IR = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, ir);
+        assert!(ir.validate().is_empty());
+
+        let Alveolus::Larvie(ref mut bird) = ir.alveolus[0] else { panic!("expected a larvie") };
+        bird.instincts[0].body.push(Stmt::Return(Some(Expr::Literal(Literal::Str("done".to_string())))));
+        assert_eq!(ir.validate().iter().map(|d| d.code).collect::<Vec<_>>(), vec![Code::TypeMismatch]);
+    }
+
+    #[test]
+    fn test_enumeration_alongside_parsed_larvies() {
+        let mut ir = parse("bug Bird gene color Color gene mood Optional<Mood> end").unwrap();
+        ir.alveolus.push(Alveolus::Enumeration(Enumeration {
+            primor: "Color".to_string(),
+            members: ["Red", "Green", "Blue"]
+                .into_iter()
+                .map(|primor| Member { primor: primor.to_string(), ..Default::default() })
+                .collect(),
+            ..Default::default()
+        }));
+
+        let diagnostics = ir.validate();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, Code::UnknownBug);
+        assert_eq!(diagnostics[0].path.to_string(), "alveolus[0].casts[1].flora");
+
+        let json = serde_json::to_string(&ir).unwrap();
+        assert!(json.contains("{\"Enumeration\":{\"primor\":\"Color\""));
+        assert_eq!(serde_json::from_str::<IR>(&json).unwrap(), ir);
+    }
+
+    #[test]
+    fn test_union_payloads_roundtrip() {
+        let mut ir = parse("bug Tree gene id Int end bug Flight gene outcome Outcome end").unwrap();
+        ir.alveolus.push(Alveolus::Union(Union {
+            primor: "Outcome".to_string(),
+            variants: vec![
+                Variant {
+                    primor: "Landed".to_string(),
+                    casts: vec![Casts::new("on", Flora::Bug("Tree".to_string()))],
+                    ..Default::default()
+                },
+                Variant { primor: "Lost".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        }));
+
+        assert!(ir.validate().is_empty());
+
+        let json = serde_json::to_string(&ir).unwrap();
+        assert!(json.contains("{\"Union\":{\"primor\":\"Outcome\",\"variants\":[{\"primor\":\"Landed\",\"casts\":"));
+        assert_eq!(serde_json::from_str::<IR>(&json).unwrap(), ir);
+    }
+
+    #[test]
+    fn test_parsed_larvie_implements_interface() {
+        let mut ir = parse("bug Bird ethics archive: Bool ethics fly(height Int) end").unwrap();
+        ir.alveolus.push(Alveolus::Interface(Interface {
+            primor: "Archivable".to_string(),
+            instincts: vec![
+                Instinct { echo: "archive".to_string(), returns: Some(Flora::Bool), ..Default::default() },
+                Instinct { echo: "fly".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        }));
+        let Alveolus::Larvie(ref mut bird) = ir.alveolus[0] else { panic!("expected a larvie") };
+        bird.implements.push("Archivable".to_string());
+
+        let diagnostics = ir.validate();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, Code::SignatureMismatch);
+        assert!(diagnostics[0].span.is_some());
+        assert_eq!(serde_json::from_str::<IR>(&serde_json::to_string(&ir).unwrap()).unwrap(), ir);
+    }
+
+    #[test]
+    fn test_flatten_parsed_larvies() {
+        let mut ir = parse("bug Animal gene name Str ethics eat end bug Bird gene wings Int ethics fly end").unwrap();
+        ir.alveolus.push(Alveolus::Mixin(Mixin {
+            primor: "Audited".to_string(),
+            casts: vec![Casts::new("created_at", Flora::DateTime)],
+            ..Default::default()
+        }));
+        let Alveolus::Larvie(ref mut bird) = ir.alveolus[1] else { panic!("expected a larvie") };
+        bird.extends = Some("Animal".to_string());
+        bird.mixins = vec!["Audited".to_string()];
+
+        assert!(ir.validate().is_empty());
+        let flat = ir.flatten().unwrap();
+
+        assert_eq!(flat.alveolus.len(), 2);
+        let Alveolus::Larvie(ref bird) = flat.alveolus[1] else { panic!("expected a larvie") };
+        let casts: Vec<&str> = bird.casts.iter().map(|cast| cast.primor.as_str()).collect();
+        assert_eq!(casts, vec!["name", "created_at", "wings"]);
+        assert_eq!(bird.instincts.iter().map(|i| i.echo.as_str()).collect::<Vec<_>>(), vec!["eat", "fly"]);
+        assert!(flat.validate().is_empty());
+    }
+
+    #[test]
+    fn test_monomorphize_parsed_generics() {
+        let ir = parse("
+            bug Page<T>
+              gene items List<T>
+              gene total U32
+            end
+            bug Bird gene name Str end
+            bug Feed gene birds Page<Bird> gene counts Page<Int> end
+        ").unwrap();
+
+        assert!(ir.validate().is_empty());
+        let mono = ir.monomorphize().unwrap();
+
+        let primors: Vec<&str> = mono.alveolus.iter().map(Alveolus::primor).collect();
+        assert_eq!(primors, vec!["Bird", "Feed", "Page_Bird", "Page_Int"]);
+        let Alveolus::Larvie(ref page) = mono.alveolus[3] else { panic!("expected a larvie") };
+        assert_eq!(page.casts[0].flora, Flora::List(Box::new(Flora::Int)));
+        assert!(mono.validate().is_empty());
+    }
+
+    #[test]
+    fn test_aliases_used_by_parsed_casts() {
+        let mut ir = parse("bug Bird gene contact Email gene energy Energy end").unwrap();
+        ir.alveolus.push(Alveolus::Alias(Alias {
+            seals: vec![Seal::Pattern("^.+@.+$".to_string())],
+            ..Alias::new("Email", Flora::Str)
+        }));
+        ir.alveolus.push(Alveolus::Alias(Alias {
+            kind: AliasKind::Nominal,
+            ..Alias::new("Energy", Flora::U16)
+        }));
+
+        assert!(ir.validate().is_empty());
+        assert_eq!(ir.underlying(&Flora::Bug("Email".to_string())), Flora::Str);
+        assert_eq!(ir.alias("Energy").unwrap().kind, AliasKind::Nominal);
+        assert_eq!(ir.alias_seals("Email"), vec![&Seal::Pattern("^.+@.+$".to_string())]);
+        assert_eq!(serde_json::from_str::<IR>(&serde_json::to_string(&ir).unwrap()).unwrap(), ir);
+    }
+
+    #[test]
+    fn test_relations_on_parsed_larvies() {
+        let mut ir = parse("bug Bird gene id Uuid end bug Tag gene label Str end").unwrap();
+        for (alveolus, primor, target, inverse) in [(0, "tags", "Tag", "birds"), (1, "birds", "Bird", "tags")] {
+            let Alveolus::Larvie(ref mut larvie) = ir.alveolus[alveolus] else { panic!("expected a larvie") };
+            larvie.casts[0].seals = vec![Seal::Core];
+            larvie.relations.push(Relation { inverse: Some(inverse.to_string()), ..Relation::new(primor, target, Cardinality::ManyToMany) });
+        }
+
+        assert!(ir.validate().is_empty());
+        let lowered = ir.lower_relations().unwrap();
+
+        let primors: Vec<&str> = lowered.alveolus.iter().map(Alveolus::primor).collect();
+        assert_eq!(primors, vec!["Bird", "Tag", "Bird_tags"]);
+        let Alveolus::Larvie(ref junction) = lowered.alveolus[2] else { panic!("expected a larvie") };
+        assert_eq!(junction.key(), vec!["bird_id", "tag_label"]);
+        assert!(lowered.validate().is_empty());
+        assert_eq!(serde_json::from_str::<IR>(&serde_json::to_string(&ir).unwrap()).unwrap(), ir);
+    }
+
+    #[test]
+    fn test_computed_casts_on_parsed_larvie() {
+        let mut ir = parse("bug Person gene first Str gene last Str gene born Int end").unwrap();
+        let Alveolus::Larvie(ref mut person) = ir.alveolus[0] else { panic!("expected a larvie") };
+        person.casts.push(Casts {
+            computed: Some(Expr::Binary {
+                op: BinOp::Sub,
+                lhs: Box::new(Expr::Literal(Literal::Int(2026))),
+                rhs: Box::new(Expr::Cast("born".to_string())),
+            }),
+            ..Casts::new("age", Flora::Int)
+        });
+
+        assert!(ir.validate().is_empty());
+        let Alveolus::Larvie(ref person) = ir.alveolus[0] else { panic!("expected a larvie") };
+        assert_eq!(person.stored_casts().count(), 3);
+        assert_eq!(person.computed_casts().map(|cast| cast.primor.as_str()).collect::<Vec<_>>(), vec!["age"]);
+        assert_eq!(serde_json::from_str::<IR>(&serde_json::to_string(&ir).unwrap()).unwrap(), ir);
+    }
+
+    #[test]
+    fn test_annotations_survive_passes_and_serde() {
+        let mut ir = parse("bug Bird gene name Str ethics fly end").unwrap();
+        let Alveolus::Larvie(ref mut bird) = ir.alveolus[0] else { panic!("expected a larvie") };
+        bird.doc = Some("A bird in the aviary.".to_string());
+        bird.casts[0].attributes.insert("json.rename".to_string(), Literal::Str("displayName".to_string()));
+        bird.instincts[0].attributes.insert("ui.hidden".to_string(), Literal::Bool(true));
+
+        assert!(ir.validate().is_empty());
+        let flat = ir.flatten().unwrap().monomorphize().unwrap();
+
+        assert_eq!(flat, ir);
+        assert_eq!(flat.alveolus[0].doc(), Some("A bird in the aviary."));
+        let Alveolus::Larvie(ref bird) = flat.alveolus[0] else { panic!("expected a larvie") };
+        assert_eq!(bird.casts[0].attribute("json.rename"), Some(&Literal::Str("displayName".to_string())));
+        assert_eq!(bird.instincts[0].attribute("ui.hidden"), Some(&Literal::Bool(true)));
+        assert_eq!(serde_json::from_str::<IR>(&serde_json::to_string(&ir).unwrap()).unwrap(), ir);
+    }
+
+    #[test]
+    fn test_modules_keep_colliding_names_apart() {
+        let team = |primor: &str, source: &str| {
+            Alveolus::Module(Module { primor: primor.to_string(), alveolus: parse(source).unwrap().alveolus, ..Default::default() })
+        };
+        let ir = IR {
+            alveolus: vec![
+                team("auth", "bug User gene name Str end"),
+                team("billing", "bug User gene balance Int end bug Invoice gene payer User end"),
+            ],
+        };
+
+        let linked = ir.link().unwrap();
+
+        let primors: Vec<&str> = linked.alveolus.iter().map(Alveolus::primor).collect();
+        assert_eq!(primors, vec!["auth::User", "billing::User", "billing::Invoice"]);
+        let Alveolus::Larvie(ref invoice) = linked.alveolus[2] else { panic!("expected a larvie") };
+        assert_eq!(invoice.casts[0].flora, Flora::Bug("billing::User".to_string()));
+        assert!(linked.validate().is_empty());
+        assert_eq!(serde_json::from_str::<IR>(&serde_json::to_string(&ir).unwrap()).unwrap(), ir);
+    }
+
+    #[test]
+    fn test_lifecycle_over_parsed_larvie() {
+        let mut ir = parse("bug Bee ethics hatch ethics molt ethics fly end").unwrap();
+        let state = |primor: &str, terminal: bool| State { primor: primor.to_string(), terminal, ..Default::default() };
+        let transition = |from: &str, to: &str, instinct: &str| Transition {
+            from: from.to_string(),
+            to: to.to_string(),
+            instinct: instinct.to_string(),
+            ..Default::default()
+        };
+        ir.alveolus.push(Alveolus::Lifecycle(Lifecycle {
+            primor: "Growth".to_string(),
+            larvie: "Bee".to_string(),
+            initial: "Egg".to_string(),
+            states: vec![state("Egg", false), state("Larva", false), state("Adult", true)],
+            transitions: vec![transition("Egg", "Larva", "hatch"), transition("Larva", "Adult", "molt"), transition("Adult", "Adult", "fly")],
+            ..Default::default()
+        }));
+
+        assert!(ir.validate().is_empty());
+        let growth = ir.lifecycles("Bee")[0];
+        assert_eq!(growth.allowed_in("fly"), vec!["Adult"]);
+        assert_eq!(growth.next("Egg", "hatch"), Some("Larva"));
+        assert_eq!(serde_json::from_str::<IR>(&serde_json::to_string(&ir).unwrap()).unwrap(), ir);
+    }
+
+    #[test]
+    fn test_events_emitted_by_parsed_instincts() {
+        let mut ir = parse("bug Egg gene laid DateTime ethics hatch ethics warm end").unwrap();
+        let Alveolus::Larvie(ref mut egg) = ir.alveolus[0] else { panic!("expected a larvie") };
+        egg.instincts[0].emits.push("Hatched".to_string());
+        ir.alveolus.push(Alveolus::Event(Event {
+            primor: "Hatched".to_string(),
+            casts: vec![Casts { seals: vec![Seal::Vital], ..Casts::new("egg", Flora::Bug("Egg".to_string())) }],
+            ..Default::default()
+        }));
+
+        assert!(ir.validate().is_empty());
+        let emitters: Vec<(&str, &str)> = ir.emitters("Hatched").into_iter().map(|(larvie, instinct)| (larvie.primor.as_str(), instinct.echo.as_str())).collect();
+        assert_eq!(emitters, vec![("Egg", "hatch")]);
+        assert_eq!(serde_json::from_str::<IR>(&serde_json::to_string(&ir).unwrap()).unwrap(), ir);
+    }
+
+    #[test]
+    fn test_view_over_parsed_larvie() {
+        let mut ir = parse("bug Bird gene name Str gene energy Int gene wings U8 end").unwrap();
+        ir.alveolus.push(Alveolus::View(View {
+            primor: "PublicBird".to_string(),
+            source: "Bird".to_string(),
+            selects: vec![
+                Selection { cast: "name".to_string(), rename: Some("title".to_string()), ..Default::default() },
+                Selection { cast: "wings".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        }));
+
+        assert!(ir.validate().is_empty());
+        let casts = ir.view_casts(ir.view("PublicBird").unwrap()).unwrap();
+        let shape: Vec<(&str, &Flora)> = casts.iter().map(|cast| (cast.primor.as_str(), &cast.flora)).collect();
+        assert_eq!(shape, vec![("title", &Flora::Str), ("wings", &Flora::U8)]);
+        assert_eq!(serde_json::from_str::<IR>(&serde_json::to_string(&ir).unwrap()).unwrap(), ir);
+    }
+    #[test]
+    fn test_bind_names_after_link() {
+        let ir = IR {
+            alveolus: vec![Alveolus::Module(Module {
+                primor: "aviary".to_string(),
+                alveolus: parse("bug Nest gene size Int end bug Bird gene nest Nest end").unwrap().alveolus,
+                ..Default::default()
+            })],
+        };
+
+        let linked = ir.link().unwrap();
+        let bindings = linked.bind_names().unwrap();
+
+        assert_eq!(bindings.references.len(), 1);
+        let nest = bindings.references[0].handle;
+        assert_eq!(bindings.symbols.get(nest).primor(), "aviary::Nest");
+        assert_eq!(bindings.symbols.kind(nest), "larvie");
+        assert_eq!(bindings.references[0].path.to_string(), "alveolus[1].casts[0].flora");
+
+        assert!(ir.bind_names().unwrap().references.is_empty());
+        assert_eq!(serde_json::from_str::<IR>(&serde_json::to_string(&ir).unwrap()).unwrap(), ir);
+    }
+
+    #[test]
+    fn test_dependency_order_of_parsed_larvies() {
+        let ir = parse("bug Bird gene nest Nest gene flock List<Bird> end bug Nest gene tree Tree end bug Tree gene height Int end").unwrap();
+
+        let graph = ir.dependencies();
+
+        let order: Vec<&str> = graph.order().into_iter().map(|handle| ir.alveolus[handle.0].primor()).collect();
+        assert_eq!(order, vec!["Tree", "Nest", "Bird"]);
+        assert_eq!(graph.cycles(), vec![vec![Handle(0)]]);
+        assert!(graph.embedding_cycles().is_empty());
+        assert!(ir.validate().is_empty());
+    }
+}